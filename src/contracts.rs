@@ -1,17 +1,38 @@
-use std::str::FromStr;
-
 use cosmwasm_std::{
-    attr, entry_point, has_coins, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order, QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg, WasmQuery
+    attr, entry_point, from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order, QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg, WasmQuery
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
 
 use url::Url;
 
+use base_minter::msg::ExecuteMsg as BaseMinterExecuteMsg;
+use base_minter::state::TOKEN_INDEX as MINTER_TOKEN_INDEX;
+use sg4::QueryMsg as BaseMinterQueryMsg;
 use sg2::msg::{CollectionParams, CreateMinterMsg, Sg2ExecuteMsg};
-use cw721::{TokensResponse, AllNftInfoResponse};
+use cw721::TokensResponse;
 use sg721::{CollectionInfo, RoyaltyInfoResponse};
 use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg, ExecuteMsg as Sg721ExecuteMsg};
-use crate::{error::ContractError, msgs::{self, Config, ExecuteMsg, InstantiateMsg, QueryMsg}, reply::handle_collection_reply, state::{Auction, Bid, SubmissionInfo, SubmissionItem, AUCTION, CONFIG, PENDING_AUCTION, SUBMISSIONS}};
+
+use crate::error::ContractError;
+use crate::migrations::migrate_from_v0_1;
+use crate::msgs::{
+    self, BidIncrement, Config, ExecuteMsg, InstantiateMsg, MigrateMsg, MinterConfigResponse, QueryMsg, RoyaltySink, RoyaltySplit,
+    TotalPowerAtHeightResponse, UpdateConfig, UpdatePauseState, VotingPowerAtHeightResponse, VotingPowerQueryMsg,
+};
+use crate::query::{
+    get_minimum_next_bid, query_artist_royalties, query_auction_by_token_id, query_auction_history, query_auctions_by_artist,
+    query_auctions_by_submitter, query_auctions_by_winner, query_bids_by_bidder, query_config, query_live_auction, query_mint_reserve,
+    query_ownership_transfer, query_pause_state, query_pending_auction_position, query_pending_auctions,
+    query_pending_auctions_by_recipient, query_pending_auctions_by_submitter, query_refunds, query_submissions,
+    query_submissions_by_recipient, query_submissions_by_submitter, query_takedown, query_takedowns, query_treasury, query_votes_by_curator,
+};
+use crate::reply::handle_collection_reply;
+use crate::state::{
+    auction_history, submissions, ArtistRoyalties, Auction, AuctionRecord, Bid, OwnershipTransfer, PauseState, RoyaltyState,
+    SubmissionInfo, SubmissionItem, Takedown, ARTIST_ROYALTIES, AUCTION, BIDDER_BIDS, CONFIG, CURATOR_VOTES, HELD_SUBMISSION_FEES, MINT_RESERVE,
+    OWNERSHIP_TRANSFER, PAUSE_STATE, PENDING_AUCTION, REFUNDS, REFUNDS_OWED, ROYALTY_STATE, TAKEDOWNS, TREASURY,
+};
 
 
 // Contract name and version used for migration.
@@ -20,13 +41,47 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 //Constants
 const COLLECTION_REPLY_ID: u64 = 1u64;
-const SECONDS_PER_DAY: u64 = 86400u64;
+pub const SECONDS_PER_DAY: u64 = 86400u64;
 const VOTE_PERIOD: u64 = 7u64;
 const AUCTION_PERIOD: u64 = 1u64;
 const CURATION_THRESHOLD: Decimal = Decimal::percent(11);
 
+//Config bounds (in days)
+const MIN_PERIOD: u64 = 1u64;
+const MAX_VOTE_PERIOD: u64 = 30u64;
+const MAX_AUCTION_PERIOD: u64 = 7u64;
+
+//Config bounds (in micro-units of their denom)
+//Only zero is rejected at the bottom, the caps are sanity checks w/ room above real deployments
+//Memecoin distributions are capped at 1B tokens (6 decimals) per auction
+const MIN_MEMECOIN_DISTRIBUTION_AMOUNT: u128 = 1u128;
+const MAX_MEMECOIN_DISTRIBUTION_AMOUNT: u128 = 1_000_000_000_000_000u128;
+//The base-minter charges its own mint price (5_000 STARS on mainnet), the cap leaves 20x headroom
+const MIN_MINT_COST: u128 = 1u128;
+const MAX_MINT_COST: u128 = 100_000_000_000u128;
+const MIN_SUBMISSION_COST: u128 = 1u128;
+const MAX_SUBMISSION_COST: u128 = 100_000_000_000u128;
+
 //Minter costs
 const MINTER_COST: u128 = 250_000_000u128;
+pub const MINT_DENOM: &str = "ustars";
+
+//Collection defaults
+const DEFAULT_COLLECTION_NAME: &str = "The Memebrane";
+const DEFAULT_COLLECTION_SYMBOL: &str = "BRANE";
+const DEFAULT_ROYALTY_SHARE: Decimal = Decimal::percent(1);
+const MAX_ROYALTY_SHARE: Decimal = Decimal::percent(10);
+
+//Bid defaults
+pub const MIN_BID_INCREMENT: BidIncrement = BidIncrement::Absolute(1u128);
+
+//Anti-sniping defaults (in seconds)
+pub const EXTENSION_WINDOW: u64 = 600u64;
+pub const EXTENSION_LENGTH: u64 = 600u64;
+pub const MAX_EXTENSION: u64 = 7200u64;
+
+//Royalty distribution defaults
+const ARTIST_ROYALTY_SHARE: Decimal = Decimal::percent(50);
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -36,34 +91,29 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    validate_mint_cost(msg.mint_cost)?;
+
+    //ustars sent above the minter creation fee seed the mint reserve
+    let sent = info.funds.iter()
+        .filter(|coin| coin.denom == MINT_DENOM)
+        .map(|coin| coin.amount.u128())
+        .sum::<u128>();
+    if sent < MINTER_COST {
+        return Err(ContractError::InvalidAsset { asset: format!("Creating the minter costs {}{}", MINTER_COST, MINT_DENOM) });
+    }
+    let mint_reserve = sent - MINTER_COST;
 
     //instantiate the Collection
     let collection_msg = Sg2ExecuteMsg::CreateMinter (CreateMinterMsg::<Option<String>> {
         init_msg: None,
-        collection_params: CollectionParams { 
-            code_id: msg.sg721_code_id, 
-            name: String::from("The Memebrane"), 
-            symbol: String::from("BRANE"), 
-            info: CollectionInfo { 
-                creator: String::from("The Memebrane Collective"), 
-                description: String::from("The Memebrane is a continuous collection created by the Memebrane Collective. It is a living, breathing, and evolving collection of digital art. The Memebrane is a place where artists can submit their braney work to append to the collection through daily auctions with all proceeds going to the submitting artist. Submissions can be new pfps, memes, portraits, etc. Let your creativity take hold of the pen!....or pencil...or stylus..you get the gist."),
-                image: todo!(), //"ipfs://CREATE AN IPFS LINK".to_string(), 
-                external_link: Some(String::from("https://twitter.com/insneinthebrane")),
-                explicit_content: Some(false), 
-                start_trading_time: None, 
-                royalty_info: Some(RoyaltyInfoResponse { 
-                    payment_address: env.contract.address.to_string(), 
-                    share: Decimal::percent(1)
-                }) 
-            }
-        }
+        collection_params: get_collection_params(deps.as_ref(), &env, msg.sg721_code_id, msg.collection_params)?,
     });
     let cosmos_msg: CosmosMsg = CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: deps.api.addr_validate(&msg.base_factory_address)?.to_string(),
         msg: to_json_binary(&collection_msg)?,
         funds: vec![
             Coin {
-                denom: String::from("ustars"),
+                denom: String::from(MINT_DENOM),
                 amount: Uint128::new(MINTER_COST),
             }
         ],
@@ -74,12 +124,16 @@ pub fn instantiate(
 
     let config = Config {
         owner: info.sender.clone(),
+        guardian: None,
+        moderators: vec![],
         bid_denom: msg.bid_denom,
         memecoin_denom: msg.memecoin_denom,
         memecoin_distribution_amount: 100_000_000u128,
-        current_token_id: 0,
-        current_submission_id: 0,
-        minter_addr: "".to_string(),
+        current_token_id: 1, //base-minter token IDs start at 1
+        current_auction_id: 0,
+        current_submission_id: 1, //0 is the first submission
+        minter_addr: "".to_string(), //set in the collection reply
+        collection_addr: "".to_string(), //set in the collection reply
         mint_cost: msg.mint_cost,
         submission_cost: 10_000_000u128,
         submission_limit: 333u64,
@@ -87,19 +141,44 @@ pub fn instantiate(
         submission_vote_period: VOTE_PERIOD,
         curation_threshold: CURATION_THRESHOLD,
         auction_period: AUCTION_PERIOD,
+        voting_power_source: None,
+        royalty_split: default_royalty_split(),
+        min_bid_increment: MIN_BID_INCREMENT,
+        extension_window: EXTENSION_WINDOW,
+        extension_length: EXTENSION_LENGTH,
+        max_extension: MAX_EXTENSION,
     };
 
     CONFIG.save(deps.storage, &config)?;
     PENDING_AUCTION.save(deps.storage, &vec![])?;
+    PAUSE_STATE.save(deps.storage, &PauseState::default())?;
+    ROYALTY_STATE.save(deps.storage, &RoyaltyState::default())?;
+    MINT_RESERVE.save(deps.storage, &mint_reserve)?;
 
-    //Set first submission start time
-    let first_submission_start_time = env.block.time.seconds() + (SECONDS_PER_DAY * VOTE_PERIOD);
-
-    //Start first Auction
+    //Start first Auction, its submission skips curation
+    let first_submission = msg.first_submission;
+    Url::parse(&first_submission.token_uri)
+        .map_err(|_| ContractError::InvalidTokenURI { uri: first_submission.token_uri.clone() })?;
+    let first_submission = SubmissionItem {
+        submission: SubmissionInfo {
+            submitter: deps.api.addr_validate(&first_submission.submitter)?,
+            proceed_recipient: deps.api.addr_validate(&first_submission.proceed_recipient)?,
+            token_uri: first_submission.token_uri,
+            reserve_price: first_submission.reserve_price,
+        },
+        curation_votes: vec![],
+        curation_power: 0,
+        submission_height: env.block.height,
+        submission_end_time: env.block.time.seconds(),
+        submission_fee: None,
+    };
     AUCTION.save(deps.storage, &Auction {
-        submission_info: msg.first_submission,
+        submission_id: 0,
+        submission_info: first_submission,
         bids: vec![],
+        auction_start_time: env.block.time.seconds(),
         auction_end_time: env.block.time.seconds() + (SECONDS_PER_DAY * config.auction_period),
+        total_extension: 0,
         highest_bid: Bid {
             bidder: Addr::unchecked(""),
             amount: 0u128,
@@ -110,10 +189,74 @@ pub fn instantiate(
         .add_submessage(submsg)
         .add_attribute("method", "instantiate")
         .add_attribute("config", format!("{:?}", config))
+        .add_attribute("mint_reserve", mint_reserve.to_string())
         .add_attribute("contract_address", env.contract.address)
     )
 }
 
+//Fill unset collection params with their defaults & validate them
+fn get_collection_params(
+    deps: Deps,
+    env: &Env,
+    code_id: u64,
+    params: msgs::CollectionParams,
+) -> Result<CollectionParams, ContractError> {
+
+    let name = params.name.unwrap_or_else(|| String::from(DEFAULT_COLLECTION_NAME));
+    let symbol = params.symbol.unwrap_or_else(|| String::from(DEFAULT_COLLECTION_SYMBOL));
+    if name.is_empty() || symbol.is_empty() {
+        return Err(ContractError::CustomError { val: "Collection name & symbol can't be empty".to_string() });
+    }
+
+    //Image must be a valid URI (ipfs, https, etc.)
+    let image = params.image;
+    Url::parse(&image).map_err(|_| ContractError::InvalidTokenURI { uri: image.clone() })?;
+
+    //No external link unless one is set
+    if let Some(external_link) = &params.external_link {
+        Url::parse(external_link).map_err(|_| ContractError::InvalidTokenURI { uri: external_link.clone() })?;
+    }
+    let description = params.description
+        .unwrap_or_else(|| format!("{} is a continuous collection of curated submissions sold through daily auctions", name));
+
+    if let Some(start_trading_time) = params.start_trading_time {
+        if start_trading_time < env.block.time {
+            return Err(ContractError::CustomError { val: "Start trading time can't be in the past".to_string() });
+        }
+    }
+
+    //Royalties are paid to the contract unless another address is set
+    let royalty_share = params.royalty_share.unwrap_or(DEFAULT_ROYALTY_SHARE);
+    if royalty_share > MAX_ROYALTY_SHARE {
+        return Err(ContractError::CustomError { val: format!("Royalty share can't be above {}", MAX_ROYALTY_SHARE) });
+    }
+    let royalty_info = if royalty_share.is_zero() {
+        None
+    } else {
+        let payment_address = match params.royalty_payment_address {
+            Some(payment_address) => deps.api.addr_validate(&payment_address)?.to_string(),
+            None => env.contract.address.to_string(),
+        };
+        Some(RoyaltyInfoResponse { payment_address, share: royalty_share })
+    };
+
+    Ok(CollectionParams {
+        code_id,
+        name,
+        symbol,
+        info: CollectionInfo {
+            //The factory sets the creator to this contract
+            creator: env.contract.address.to_string(),
+            description,
+            image,
+            external_link: params.external_link,
+            explicit_content: Some(params.explicit_content.unwrap_or(false)),
+            start_trading_time: params.start_trading_time,
+            royalty_info,
+        },
+    })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -122,12 +265,25 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::SubmitNFT { submitter, proceed_recipient, token_uri } => submit_nft(deps, env, info, proceed_recipient, token_uri),
+        ExecuteMsg::SubmitNFT { submitter: _, proceed_recipient, token_uri, reserve_price } => submit_nft(deps, env, info, proceed_recipient, token_uri, reserve_price),
         ExecuteMsg::VoteToCurate { submission_ids, vote } => curate_nft(deps, env, info, submission_ids, vote),
         ExecuteMsg::Bid {  } => bid_on_live_auction(deps, env, info),
         ExecuteMsg::ConcludeAuction {  } => conclude_auction(deps, env, info),
-        ExecuteMsg::MigrateMinter { new_address } => todo!(),
-        ExecuteMsg::UpdateConfig { owner, bid_denom, memecoin_denom, minter_addr } => todo!(),
+        ExecuteMsg::ClaimRoyalties {} => claim_royalties(deps, info),
+        ExecuteMsg::ClaimRefunds {} => claim_refunds(deps, info),
+        ExecuteMsg::FundMintReserve {} => fund_mint_reserve(deps, info),
+        ExecuteMsg::CancelAuction { reason, return_to_curation } => cancel_auction(deps, env, info, reason, return_to_curation),
+        ExecuteMsg::DistributeRoyalties { denom } => distribute_royalties(deps, env, info, denom),
+        ExecuteMsg::MigrateMinter { new_address, old_minter_successor } => migrate_minter(deps, env, info, new_address, old_minter_successor),
+        ExecuteMsg::UpdateConfig(update) => update_config(deps, info, update),
+        ExecuteMsg::ProposeNewOwner { new_owner, expiry } => propose_new_owner(deps, env, info, new_owner, expiry),
+        ExecuteMsg::AcceptOwnership {} => accept_ownership(deps, env, info),
+        ExecuteMsg::CancelOwnershipTransfer {} => cancel_ownership_transfer(deps, info),
+        ExecuteMsg::UpdateModerators { add, remove } => update_moderators(deps, info, add, remove),
+        ExecuteMsg::TakeDownSubmission { submission_id, reason, refund_fee } => take_down_submission(deps, env, info, submission_id, reason, refund_fee),
+        ExecuteMsg::WithdrawTreasury { denom, amount, recipient } => withdraw_treasury(deps, info, denom, amount, recipient),
+        ExecuteMsg::UpdateGuardian { guardian } => update_guardian(deps, info, guardian),
+        ExecuteMsg::UpdatePauseState(update) => update_pause_state(deps, info, update),
     }
 }
 
@@ -151,30 +307,34 @@ fn submit_nft(
     info: MessageInfo,
     proceed_recipient: String,
     token_uri: String,
+    reserve_price: Option<u128>,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, |pause_state| pause_state.submissions, "submissions")?;
     let mut config = CONFIG.load(deps.storage)?;
-    let mut msgs: Vec<CosmosMsg> = vec![];
     
     // Token URI must be a valid URL (ipfs, https, etc.)
-    Url::parse(&token_uri).map_err(|_| ContractError::InvalidTokenURI { uri: token_uri })?;
+    Url::parse(&token_uri).map_err(|_| ContractError::InvalidTokenURI { uri: token_uri.clone() })?;
 
-    //If submission is from a non-holder, it costs Some(bid_asset)
-    if let Err(_) = check_if_collection_holder(deps.as_ref(), config.clone().minter_addr, info.clone().sender){
-        if !has_coins(&info.funds, &Coin {
+    //If submission is from a non-holder, it costs exactly Some(bid_asset)
+    let mut submission_fee = None;
+    if check_if_collection_holder(deps.as_ref(), config.clone().collection_addr, info.clone().sender).is_err() {
+        if info.funds != vec![Coin {
             denom: config.bid_denom.clone(),
             amount: Uint128::new(config.submission_cost),
-        }) {
-            return Err(ContractError::CustomError { val: "Submission cost not sent".to_string() });
+        }] {
+            return Err(ContractError::CustomError { val: format!("Submission cost is exactly {}{}", config.submission_cost, config.bid_denom) });
         }
 
-        //Send the bid asset to the owner
-        msgs.push(CosmosMsg::Bank(BankMsg::Send {
-            to_address: config.owner.to_string(),
-            amount: vec![Coin {
-                denom: config.bid_denom.clone(),
-                amount: Uint128::new(config.submission_cost),
-            }],
-        }));
+        //Hold the fee until the submission is finalized
+        let fee = Coin {
+            denom: config.bid_denom.clone(),
+            amount: Uint128::new(config.submission_cost),
+        };
+        hold_submission_fee(deps.storage, &fee)?;
+        submission_fee = Some(fee);
+    } else if !info.funds.is_empty() {
+        //Holders submit for free, funds would be stuck in the contract
+        return Err(ContractError::CustomError { val: "Collection holders don't pay a submission cost".to_string() });
     };
 
     //Create a new submission
@@ -184,12 +344,16 @@ fn submit_nft(
             submitter: info.sender.clone(),
             proceed_recipient: deps.api.addr_validate(&proceed_recipient)?,
             token_uri,
+            reserve_price,
         },
         curation_votes: vec![],
+        curation_power: 0,
+        submission_height: env.block.height,
         submission_end_time: env.block.time.seconds() + (config.submission_vote_period * SECONDS_PER_DAY),
+        submission_fee,
     };
 
-    SUBMISSIONS.save(deps.storage, submission_id, &submission_info)?;
+    submissions().save(deps.storage, submission_id, &submission_info)?;
 
     Ok(Response::new()
         .add_attribute("method", "submit_nft")
@@ -201,13 +365,13 @@ fn submit_nft(
 
 fn check_if_collection_holder(
     deps: Deps,
-    minter_addr: String,
+    collection_addr: String,
     sender: Addr,
 ) -> Result<(), ContractError> {  
 
     //Check if the sender is a collection holder
     let token_info: TokensResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: minter_addr,
+        contract_addr: collection_addr,
         msg: to_json_binary(&Sg721QueryMsg::Tokens { owner: sender.to_string(), start_after: None, limit: None })?,
     })).map_err(|_| ContractError::CustomError { val: "Failed to query collection, sender may not hold an NFT".to_string() })?;
 
@@ -218,6 +382,57 @@ fn check_if_collection_holder(
     Ok(())
 }
 
+//Get the total curation voting power
+fn get_total_power(
+    deps: Deps,
+    config: &Config,
+    height: u64,
+) -> Result<u128, ContractError> {
+    match config.voting_power_source.clone() {
+        //Total staked power at the height
+        Some(voting_power_source) => {
+            let total_power: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+                voting_power_source,
+                &VotingPowerQueryMsg::TotalPowerAtHeight { height: Some(height) },
+            )?;
+
+            Ok(total_power.power.u128())
+        },
+        //1 vote per token in the collection
+        None => {
+            let all_token_info: TokensResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                contract_addr: config.clone().collection_addr,
+                msg: to_json_binary(&Sg721QueryMsg::AllTokens { start_after: None, limit: None })?,
+            }))?;
+
+            Ok(all_token_info.tokens.len() as u128)
+        },
+    }
+}
+
+//Get the curator's voting power, holders get 1 vote without a voting power source
+fn get_voting_power(
+    deps: Deps,
+    config: &Config,
+    curator: &Addr,
+    height: u64,
+) -> Result<u128, ContractError> {
+    match config.voting_power_source.clone() {
+        Some(voting_power_source) => {
+            let voting_power: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+                voting_power_source,
+                &VotingPowerQueryMsg::VotingPowerAtHeight { address: curator.to_string(), height: Some(height) },
+            )?;
+            if voting_power.power.is_zero() {
+                return Err(ContractError::CustomError { val: format!("Sender had no staked voting power at height {}", height) });
+            }
+
+            Ok(voting_power.power.u128())
+        },
+        None => Ok(1u128),
+    }
+}
+
 fn curate_nft(
     deps: DepsMut,
     env: Env,
@@ -225,6 +440,7 @@ fn curate_nft(
     submission_ids: Vec<u64>,
     vote: bool,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, |pause_state| pause_state.curation, "curation")?;
     let mut config = CONFIG.load(deps.storage)?;
 
     //Check if the submission is valid
@@ -232,32 +448,32 @@ fn curate_nft(
         return Err(ContractError::CustomError { val: "Exceeded submission limit".to_string() });
     }
 
-    //Make sure the sender is a collection holder
-    check_if_collection_holder(deps.as_ref(), config.clone().minter_addr, info.clone().sender)?;
-
-
-    //Get total votes
-    let all_token_info: TokensResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: config.clone().minter_addr,
-        msg: to_json_binary(&Sg721QueryMsg::AllTokens { start_after: None, limit: None })?,
-    }))?;
-    let total_votes = all_token_info.tokens.len();
-    let passing_threshold = (Uint128::new(total_votes as u128) * config.curation_threshold).u128();
+    //Without a voting power source, make sure the sender is a collection holder
+    if config.voting_power_source.is_none() {
+        check_if_collection_holder(deps.as_ref(), config.clone().collection_addr, info.clone().sender)?;
+    }
 
     //Update the submission info
-    for submission_id in submission_ids {
+    for submission_id in submission_ids.clone() {
         //Load submission info
-        let mut submission_info = SUBMISSIONS.load(deps.storage, submission_id)?;
+        let mut submission_info = submissions().load(deps.storage, submission_id)?;
     
         // Assert they haven't voted yet
         if submission_info.curation_votes.contains(&info.clone().sender) {
             continue;
         }
+
+        //Get total voting power at the submission's height
+        let total_power = get_total_power(deps.as_ref(), &config, submission_info.submission_height)?;
+        let passing_threshold = (Uint128::new(total_power) * config.curation_threshold).u128();
+
         /// Assert the submission is still in the voting period
         //If its past the submission period and the submission doesn't have enough votes, remove it
         if env.block.time.seconds() > submission_info.submission_end_time {
-            if submission_info.curation_votes.len() < passing_threshold as usize {
-                SUBMISSIONS.remove(deps.storage, submission_id);
+            if submission_info.curation_power < passing_threshold {
+                submissions().remove(deps.storage, submission_id)?;
+                //The fee is kept by the treasury
+                release_submission_fee(deps.storage, &submission_info, false)?;
                 //Subtract from the submission total
                 config.submission_total -= 1;
                 continue;
@@ -267,16 +483,22 @@ fn curate_nft(
         else {            
             //Tally the vote
             if vote {
+                let voting_power = get_voting_power(deps.as_ref(), &config, &info.sender, submission_info.submission_height)?;
                 submission_info.curation_votes.push(info.sender.clone());
+                submission_info.curation_power += voting_power;
+                CURATOR_VOTES.save(deps.storage, (&info.sender, submission_id), &true)?;
                 
                 //If the submission has enough votes, add it to the list of auctionables
-                if submission_info.curation_votes.len() < passing_threshold as usize {
+                if submission_info.curation_power >= passing_threshold {
                     //Set as live auction if there is none, else add to pending auctions
-                    if let Err(_) = AUCTION.load(deps.storage) {
+                    if AUCTION.load(deps.storage).is_err() {
                         AUCTION.save(deps.storage, &Auction {
+                            submission_id,
                             submission_info: submission_info.clone(),
                             bids: vec![],
+                            auction_start_time: env.block.time.seconds(),
                             auction_end_time: env.block.time.seconds() + (SECONDS_PER_DAY * config.clone().auction_period),
+                            total_extension: 0,
                             highest_bid: Bid {
                                 bidder: Addr::unchecked(""),
                                 amount: 0u128,                            
@@ -286,9 +508,12 @@ fn curate_nft(
 
                         PENDING_AUCTION.update(deps.storage, |mut auctions| -> Result<_, ContractError> {
                             auctions.push(Auction {
+                                submission_id,
                                 submission_info: submission_info.clone(),
                                 bids: vec![],
+                                auction_start_time: 0, //will set when active
                                 auction_end_time: 0, //will set when active
+                                total_extension: 0,
                                 highest_bid: Bid {
                                     bidder: Addr::unchecked(""),
                                     amount: 0u128,                            
@@ -297,12 +522,12 @@ fn curate_nft(
                             Ok(auctions)
                         })?;
                     }
-                    SUBMISSIONS.remove(deps.storage, submission_id);
+                    submissions().remove(deps.storage, submission_id)?;
                     //Subtract from the submission total
                     config.submission_total -= 1;
                 } else {
                     //If the submission doesn't have enough votes yet, save it
-                    SUBMISSIONS.save(deps.storage, submission_id, &submission_info)?;                
+                    submissions().save(deps.storage, submission_id, &submission_info)?;                
                 }
             }
         }
@@ -321,7 +546,7 @@ fn curate_nft(
 }
 
 fn assert_bid_asset(
-    deps: Deps,
+    _deps: Deps,
     info: &MessageInfo,
     bid_denom: String,    
 ) -> Result<Bid, ContractError> {
@@ -344,56 +569,109 @@ fn bid_on_live_auction(
     env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, |pause_state| pause_state.bidding, "bidding")?;
     //Load config
     let config = CONFIG.load(deps.storage)?;
     //Assert funds are the bid asset
-    let current_bid = assert_bid_asset(deps.as_ref(), &info, config.bid_denom)?;
-    //Initialize msgs
-    let mut msgs: Vec<CosmosMsg> = vec![];
+    let current_bid = assert_bid_asset(deps.as_ref(), &info, config.bid_denom.clone())?;
 
     //This will be initiated in the instantiate function & refreshed at the end of the conclude_auction function
-    let mut live_auction = AUCTION.load(deps.storage)?;
+    let mut live_auction = AUCTION.may_load(deps.storage)?
+        .ok_or_else(|| ContractError::CustomError { val: "No live auction".to_string() })?;
 
     //Check if the auction is still live
     if env.block.time.seconds() > live_auction.auction_end_time {
         return Err(ContractError::CustomError { val: "Auction has ended".to_string() });
     }
 
-    //Check if the bid is higher than the current highest bid
-    if let Some(highest_bid) = live_auction.bids.last() {
-        if current_bid.amount <= highest_bid.amount {
-            return Err(ContractError::CustomError { val: "Bid is lower than the current highest bid".to_string() });
-        } else {
-            //Add the bid to the auction's bid list
-            live_auction.bids.push(current_bid);
-
-            //Send the previous highest bid back to the bidder
-            msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: highest_bid.bidder.to_string(),
-                    amount: vec![Coin {
-                        denom: config.bid_denom,
-                        amount: Uint128::new(highest_bid.amount),
-                    }],
-                }));
-
-            //Set bid as highest bid
-            live_auction.highest_bid = current_bid;
+    //Bidders only send the difference, their claimable refund is added to the bid
+    let refund_used = take_refund(deps.storage, &info.sender, &config.bid_denom)?;
+    let current_bid = Bid {
+        bidder: info.sender.clone(),
+        amount: current_bid.amount + refund_used,
+    };
+    //The highest bidder raises their escrowed bid
+    let outbid = live_auction.highest_bid.clone();
+    let is_top_up = outbid.amount > 0 && outbid.bidder == info.sender;
+    let current_bid = if is_top_up {
+        Bid {
+            bidder: info.sender.clone(),
+            amount: outbid.amount + current_bid.amount,
         }
+    } else {
+        current_bid
+    };
+
+    //Every bid, the first included, must meet the minimum
+    let minimum_bid = get_minimum_next_bid(&config, &live_auction);
+    if current_bid.amount < minimum_bid {
+        return Err(ContractError::CustomError { val: format!("Bid must be at least {}", minimum_bid) });
+    }
+
+    //The escrow only holds the highest bid, so the outbid one is credited as a claimable refund.
+    //No highest bid (amount 0) means nothing is escrowed & top-ups stay escrowed.
+    if outbid.amount > 0 && !is_top_up {
+        credit_refund(deps.storage, &outbid.bidder, &config.bid_denom, outbid.amount)?;
     }
+
+    //Escrow the new highest bid
+    BIDDER_BIDS.update(deps.storage, (&info.sender, live_auction.submission_id), |bids| -> StdResult<_> {
+        let mut bids = bids.unwrap_or_default();
+        bids.push(current_bid.amount);
+        Ok(bids)
+    })?;
+    live_auction.bids.push(current_bid.clone());
+    live_auction.highest_bid = current_bid.clone();
+
+    //Late bids extend the auction
+    extend_auction(&config, &mut live_auction, env.block.time.seconds());
+
     AUCTION.save(deps.storage, &live_auction)?;
 
     Ok(Response::new()
-        .add_messages(msgs)
         .add_attribute("method", "bid_on_live_auction")
         .add_attribute("bidder", info.sender)
         .add_attribute("bid", current_bid.amount.to_string())
+        .add_attribute("top_up", is_top_up.to_string())
+        .add_attribute("refund_used", refund_used.to_string())
+        .add_attribute("outbid_bidder", if outbid.amount > 0 { outbid.bidder.to_string() } else { String::from("none") })
+        .add_attribute("refunded_bid", if is_top_up { String::from("0") } else { outbid.amount.to_string() })
+        .add_attribute("auction_end_time", live_auction.auction_end_time.to_string())
     )
 }
 
+//Push the end time out for bids within the extension window, up to the max extension
+fn extend_auction(
+    config: &Config,
+    auction: &mut Auction,
+    now: u64,
+) {
+    if auction.auction_end_time.saturating_sub(now) >= config.extension_window {
+        return;
+    }
+    let original_end_time = auction.auction_end_time - auction.total_extension;
+    let new_end_time = (now + config.extension_length)
+        .min(original_end_time + config.max_extension)
+        .max(auction.auction_end_time);
+
+    auction.total_extension = new_end_time - original_end_time;
+    auction.auction_end_time = new_end_time;
+}
+
 fn get_bid_ratios(
-    bids: &Vec<Bid>
+    bids: &[Bid]
 ) -> Vec<(Addr, Decimal)> {
     let mut bid_ratios: Vec<(Addr, Decimal)> = vec![];
+
+    //Top-ups record the raised bid, so it replaces the bid it raised.
+    //Only the highest bidder can top up, i.e. the bidder of the previous bid.
+    let bids = bids.iter().fold(vec![], |mut acc: Vec<Bid>, bid| {
+        match acc.last_mut() {
+            Some(last_bid) if last_bid.bidder == bid.bidder => last_bid.amount = bid.amount,
+            _ => acc.push(bid.clone()),
+        }
+        acc
+    });
     let total_bids = bids.iter().fold(0u128, |acc, bid| acc + bid.amount);
 
     //Aggregate bids of the same bidder
@@ -417,51 +695,88 @@ fn get_bid_ratios(
 fn conclude_auction(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
+    _info: MessageInfo,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, |pause_state| pause_state.settlement, "settlement")?;
     //Load config
-    let config = CONFIG.load(deps.storage)?;
+    let mut config = CONFIG.load(deps.storage)?;
     //Initialize msgs
     let mut msgs: Vec<CosmosMsg> = vec![];
     //Load live auction
-    let mut live_auction = AUCTION.load(deps.storage)?;
+    let live_auction = AUCTION.load(deps.storage)?;
 
     //Check if the auction is still live
     if env.block.time.seconds() < live_auction.auction_end_time {
         return Err(ContractError::CustomError { val: "Auction is still live".to_string() });
     }
 
+    //Initialize the auction's history record
+    let mut record = new_auction_record(&config, &live_auction);
+
+    //Bids below the reserve price settle unsold & the highest bid is refunded
+    let reserve_price = live_auction.submission_info.submission.reserve_price.unwrap_or_default();
+    let meets_reserve = live_auction.highest_bid.amount > 0 && live_auction.highest_bid.amount >= reserve_price;
+    //The mint fee is paid from the mint reserve only, if it can't cover it the auction settles unsold too so the queue keeps moving
+    let mint_reserve = MINT_RESERVE.may_load(deps.storage)?.unwrap_or_default();
+    let mint_reserve_short = meets_reserve && mint_reserve < config.mint_cost;
+    let sold = meets_reserve && !mint_reserve_short;
+    if live_auction.highest_bid.amount > 0 && !sold {
+        credit_refund(deps.storage, &live_auction.highest_bid.bidder, &config.bid_denom, live_auction.highest_bid.amount)?;
+    }
+
+    //The submission is finalized, its fee is kept by the treasury
+    release_submission_fee(deps.storage, &live_auction.submission_info, false)?;
+
     //Mint the NFT & send the bid to the proceed_recipient
-    if live_auction.highest_bid.amount > 0 {
-        //Mint the NFT to the highest bidder
+    if sold {
+        MINT_RESERVE.save(deps.storage, &(mint_reserve - config.mint_cost))?;
+        //The minter increments its token index on each mint
+        let token_id = config.current_token_id.to_string();
+
+        //Mint the NFT to the contract, base-minter only mints to the collection creator
         msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: config.minter_addr,
-            msg: to_json_binary(&Sg721ExecuteMsg::Mint::<Option<String>, Option<String>> {
-                owner: live_auction.highest_bid.bidder.to_string(),
-                token_id: config.current_token_id.to_string(),
-                token_uri: Some(live_auction.submission_info.submission.token_uri.clone()),
-                extension: None,
+            contract_addr: config.minter_addr.clone(),
+            msg: to_json_binary(&BaseMinterExecuteMsg::Mint {
+                token_uri: live_auction.submission_info.submission.token_uri.clone(),
             })?,
             funds: vec![
                 Coin {
-                    denom: String::from("ustars"),
+                    denom: String::from(MINT_DENOM),
                     amount: Uint128::new(config.mint_cost),
                 }],
         }));
+        //Transfer the NFT to the highest bidder
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.collection_addr.clone(),
+            msg: to_json_binary(&Sg721ExecuteMsg::TransferNft::<Option<String>, Option<String>> {
+                recipient: live_auction.highest_bid.bidder.to_string(),
+                token_id: token_id.clone(),
+            })?,
+            funds: vec![],
+        }));
+        //Increment token ID
+        config.current_token_id += 1;
 
         //Send the highest bid to the proceed_recipient
         msgs.push(CosmosMsg::Bank(BankMsg::Send {
             to_address: live_auction.submission_info.submission.proceed_recipient.to_string(),
             amount: vec![Coin {
-                denom: config.bid_denom,
+                denom: config.bid_denom.clone(),
                 amount: Uint128::new(live_auction.highest_bid.amount),
             }],
         }));
 
+        record.token_id = Some(token_id);
+        record.winner = Some(live_auction.highest_bid.bidder.clone());
+        record.winning_amount = live_auction.highest_bid.amount;
+
+        //The proceed_recipient earns royalties on the sold token
+        add_artist_token(deps.storage, &live_auction.submission_info.submission.proceed_recipient)?;
+
         /////Send memecoins to Bidders & curators
-        if let Some(meme_denom) = config.memecoin_denom {
+        if let Some(meme_denom) = config.memecoin_denom.clone() {
             //Get memecoin distribution amount
-            let memecoin_distribution_amount = match deps.querier.query_balance(env.contract.address, config.memecoin_denom){
+            let memecoin_distribution_amount = match deps.querier.query_balance(env.contract.address.clone(), meme_denom.clone()){
                 Ok(balance) => {
                     //We distribute the config amount or half of the balance, whichever is lower
                     if balance.amount.u128() / 2 < config.memecoin_distribution_amount {
@@ -476,7 +791,7 @@ fn conclude_auction(
             //Get bidder pro_rata distribution
             let bid_ratios = get_bid_ratios(&live_auction.bids);            
             //Split total memecoins between bidders (pro_rata to bid_amount)
-            let meme_to_bidders = bid_ratios.iter().map(|bidder| {
+            let meme_to_bidders = bid_ratios.into_iter().map(|bidder| {
                 let meme_amount = (Uint128::new(memecoin_distribution_amount) * bidder.1).u128();
                 (bidder.0, Coin {
                     denom: meme_denom.clone(),
@@ -485,7 +800,7 @@ fn conclude_auction(
             }).collect::<Vec<(Addr, Coin)>>();
             //Split total memecoins between curators (1/len)
             let meme_to_curators = live_auction.submission_info.curation_votes.iter().map(|curator| {
-                let meme_amount = (Uint128::new(memecoin_distribution_amount) * Decimal::from_ratio(1, live_auction.submission_info.curation_votes.len() as u128)).u128();
+                let meme_amount = (Uint128::new(memecoin_distribution_amount) * Decimal::from_ratio(1u128, live_auction.submission_info.curation_votes.len() as u128)).u128();
                 (curator.clone(), Coin {
                     denom: meme_denom.clone(),
                     amount: Uint128::new(meme_amount),
@@ -493,15 +808,13 @@ fn conclude_auction(
             }).collect::<Vec<(Addr, Coin)>>();
 
             //Create the memecoin distribution msgs
-            for (bidder, coin) in meme_to_bidders {
-                msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: bidder.to_string(),
-                    amount: vec![coin],
-                }));
-            }
-            for (curator, coin) in meme_to_curators {
+            for (recipient, coin) in meme_to_bidders.into_iter().chain(meme_to_curators) {
+                if coin.amount.is_zero() {
+                    continue;
+                }
+                record.memecoins_distributed += coin.amount.u128();
                 msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: curator.to_string(),
+                    to_address: recipient.to_string(),
                     amount: vec![coin],
                 }));
             }
@@ -509,24 +822,1010 @@ fn conclude_auction(
         
     }
 
-    //Set the new auction to the next pending auction
-    if let Some(mut next_auction) = PENDING_AUCTION.load(deps.storage)?.pop() {
-        //set auction end time
+    //Archive the concluded auction
+    auction_history().save(deps.storage, record.auction_id, &record)?;
+    config.current_auction_id += 1;
+    CONFIG.save(deps.storage, &config)?;
+
+    start_next_auction(deps.storage, &env, &config)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("method", "conclude_auction")
+        .add_attribute("auction_id", record.auction_id.to_string())
+        .add_attribute("highest_bidder", if live_auction.highest_bid.amount > 0 { live_auction.highest_bid.bidder.to_string() } else { String::from("none") })
+        .add_attribute("highest_bid", live_auction.highest_bid.amount.to_string())
+        .add_attribute("sold", sold.to_string())
+        .add_attribute("mint_reserve_short", mint_reserve_short.to_string())
+    )
+}
+
+pub fn default_royalty_split() -> RoyaltySplit {
+    RoyaltySplit {
+        artist_share: ARTIST_ROYALTY_SHARE,
+        sinks: vec![],
+    }
+}
+
+//Contract funds of the denom that aren't free to spend
+fn get_reserved_funds(
+    storage: &dyn Storage,
+    config: &Config,
+    denom: &str,
+) -> StdResult<u128> {
+    //Treasury funds
+    let mut reserved = TREASURY.may_load(storage, denom)?.unwrap_or_default();
+    //Claimable bid refunds, kept in their denom if the bid_denom changes
+    reserved += REFUNDS_OWED.may_load(storage, denom)?.unwrap_or_default();
+    //Fees of submissions that aren't finalized yet
+    reserved += HELD_SUBMISSION_FEES.may_load(storage, denom)?.unwrap_or_default();
+
+    if denom == config.bid_denom {
+        //Escrowed highest bid
+        if let Some(live_auction) = AUCTION.may_load(storage)? {
+            reserved += live_auction.highest_bid.amount;
+        }
+        //Unclaimed artist royalties
+        reserved += ROYALTY_STATE.load(storage)?.artist_owed;
+    }
+    //Funds set aside for mint fees
+    if denom == MINT_DENOM {
+        reserved += MINT_RESERVE.may_load(storage)?.unwrap_or_default();
+    }
+
+    Ok(reserved)
+}
+
+//Settle the artist's royalties & add a sold token
+fn add_artist_token(
+    storage: &mut dyn Storage,
+    artist: &Addr,
+) -> StdResult<()> {
+    let mut royalty_state = ROYALTY_STATE.load(storage)?;
+    let mut artist_royalties = ARTIST_ROYALTIES.may_load(storage, artist)?
+        .unwrap_or(ArtistRoyalties { token_count: 0, index: royalty_state.index, unclaimed: 0 });
+
+    settle_artist_royalties(&mut artist_royalties, royalty_state.index);
+    artist_royalties.token_count += 1;
+    royalty_state.token_count += 1;
+
+    ARTIST_ROYALTIES.save(storage, artist, &artist_royalties)?;
+    ROYALTY_STATE.save(storage, &royalty_state)?;
+
+    Ok(())
+}
+
+fn settle_artist_royalties(
+    artist_royalties: &mut ArtistRoyalties,
+    index: Decimal,
+) {
+    let accrued = Uint128::from(artist_royalties.token_count) * (index - artist_royalties.index);
+    artist_royalties.unclaimed += accrued.u128();
+    artist_royalties.index = index;
+}
+
+fn distribute_royalties(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut royalty_state = ROYALTY_STATE.load(deps.storage)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    let denom = denom.unwrap_or_else(|| config.bid_denom.clone());
+    //The memecoin balance funds auction distributions
+    if Some(denom.clone()) == config.memecoin_denom {
+        return Err(ContractError::CustomError { val: "Memecoins aren't royalties".to_string() });
+    }
+
+    //Royalties are whatever balance of the denom isn't reserved
+    let balance = deps.querier.query_balance(env.contract.address, denom.clone())?.amount.u128();
+    let royalties = balance.saturating_sub(get_reserved_funds(deps.storage, &config, &denom)?);
+    if royalties == 0 {
+        return Err(ContractError::CustomError { val: "No royalties to distribute".to_string() });
+    }
+    let mut remaining = royalties;
+
+    //Accrue the artist share per sold token, the treasury gets it if nothing has sold or it isn't in the bid_denom
+    let mut artist_amount = 0u128;
+    if royalty_state.token_count > 0 && denom == config.bid_denom {
+        artist_amount = (Uint128::new(royalties) * config.royalty_split.artist_share).u128();
+        royalty_state.index += Decimal::from_ratio(artist_amount, royalty_state.token_count);
+        royalty_state.artist_owed += artist_amount;
+        remaining -= artist_amount;
+        ROYALTY_STATE.save(deps.storage, &royalty_state)?;
+    }
+
+    //Send each sink its share
+    for sink in config.royalty_split.sinks.iter() {
+        let amount = (Uint128::new(royalties) * sink.share).u128();
+        if amount == 0 {
+            continue;
+        }
+        remaining -= amount;
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: sink.address.to_string(),
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount: Uint128::new(amount),
+            }],
+        }));
+    }
+
+    //The rest goes to the treasury
+    credit_treasury(deps.storage, &denom, remaining)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("method", "distribute_royalties")
+        .add_attribute("denom", denom)
+        .add_attribute("royalties", royalties.to_string())
+        .add_attribute("artist_amount", artist_amount.to_string())
+        .add_attribute("treasury_amount", remaining.to_string())
+    )
+}
+
+fn credit_treasury(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: u128,
+) -> StdResult<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    TREASURY.update(storage, denom, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + amount)
+    })?;
+
+    Ok(())
+}
+
+fn debit_treasury(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: u128,
+) -> Result<(), ContractError> {
+    let balance = TREASURY.may_load(storage, denom)?.unwrap_or_default();
+    if amount > balance {
+        return Err(ContractError::CustomError { val: format!("Treasury only has {}{}", balance, denom) });
+    }
+    TREASURY.save(storage, denom, &(balance - amount))?;
+
+    Ok(())
+}
+
+fn hold_submission_fee(
+    storage: &mut dyn Storage,
+    fee: &Coin,
+) -> StdResult<()> {
+    HELD_SUBMISSION_FEES.update(storage, &fee.denom, |held| -> StdResult<_> {
+        Ok(held.unwrap_or_default() + fee.amount.u128())
+    })?;
+
+    Ok(())
+}
+
+//Release a finalized submission's held fee, it's credited to the treasury unless refunded.
+//Returns the fee to refund.
+fn release_submission_fee(
+    storage: &mut dyn Storage,
+    submission: &SubmissionItem,
+    refund: bool,
+) -> StdResult<Option<Coin>> {
+    let fee = match submission.submission_fee.clone() {
+        Some(fee) => fee,
+        None => return Ok(None),
+    };
+    let held = HELD_SUBMISSION_FEES.may_load(storage, &fee.denom)?.unwrap_or_default();
+    HELD_SUBMISSION_FEES.save(storage, &fee.denom, &(held - fee.amount.u128()))?;
+
+    if refund {
+        return Ok(Some(fee));
+    }
+    credit_treasury(storage, &fee.denom, fee.amount.u128())?;
+
+    Ok(None)
+}
+
+fn withdraw_treasury(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    amount: Option<u128>,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    //Only treasury funds can be spent, escrow & reserves aren't in the ledger
+    let amount = match amount {
+        Some(amount) => amount,
+        None => TREASURY.may_load(deps.storage, &denom)?.unwrap_or_default(),
+    };
+    if amount == 0 {
+        return Err(ContractError::CustomError { val: "Nothing to withdraw".to_string() });
+    }
+    debit_treasury(deps.storage, &denom, amount)?;
+
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => config.owner,
+    };
+
+    Ok(Response::new()
+        .add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount: Uint128::new(amount),
+            }],
+        }))
+        .add_attribute("method", "withdraw_treasury")
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", format!("{}{}", amount, denom))
+    )
+}
+
+fn claim_royalties(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut royalty_state = ROYALTY_STATE.load(deps.storage)?;
+    let mut artist_royalties = ARTIST_ROYALTIES.may_load(deps.storage, &info.sender)?
+        .ok_or_else(|| ContractError::CustomError { val: "Sender has no sold tokens".to_string() })?;
+
+    settle_artist_royalties(&mut artist_royalties, royalty_state.index);
+    let claimed = artist_royalties.unclaimed;
+    if claimed == 0 {
+        return Err(ContractError::CustomError { val: "No royalties to claim".to_string() });
+    }
+    artist_royalties.unclaimed = 0;
+    royalty_state.artist_owed -= claimed;
+
+    ARTIST_ROYALTIES.save(deps.storage, &info.sender, &artist_royalties)?;
+    ROYALTY_STATE.save(deps.storage, &royalty_state)?;
+
+    Ok(Response::new()
+        .add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: config.bid_denom,
+                amount: Uint128::new(claimed),
+            }],
+        }))
+        .add_attribute("method", "claim_royalties")
+        .add_attribute("artist", info.sender)
+        .add_attribute("claimed", claimed.to_string())
+    )
+}
+
+fn credit_refund(
+    storage: &mut dyn Storage,
+    bidder: &Addr,
+    denom: &str,
+    amount: u128,
+) -> StdResult<()> {
+    REFUNDS.update(storage, (bidder, denom), |refund| -> StdResult<_> {
+        Ok(refund.unwrap_or_default() + amount)
+    })?;
+    REFUNDS_OWED.update(storage, denom, |owed| -> StdResult<_> {
+        Ok(owed.unwrap_or_default() + amount)
+    })?;
+
+    Ok(())
+}
+
+//Remove the bidder's claimable refund of the denom, returning its amount
+fn take_refund(
+    storage: &mut dyn Storage,
+    bidder: &Addr,
+    denom: &str,
+) -> StdResult<u128> {
+    let refund = REFUNDS.may_load(storage, (bidder, denom))?.unwrap_or_default();
+    if refund == 0 {
+        return Ok(0);
+    }
+    REFUNDS.remove(storage, (bidder, denom));
+    REFUNDS_OWED.update(storage, denom, |owed| -> StdResult<_> {
+        Ok(owed.unwrap_or_default() - refund)
+    })?;
+
+    Ok(refund)
+}
+
+//Not pausable, refunds are the bidders' funds
+fn claim_refunds(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let refunds = REFUNDS
+        .prefix(&info.sender)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, u128)>>>()?;
+    if refunds.is_empty() {
+        return Err(ContractError::CustomError { val: "No refunds to claim".to_string() });
+    }
+
+    let mut claimed: Vec<Coin> = vec![];
+    for (denom, amount) in refunds {
+        REFUNDS.remove(deps.storage, (&info.sender, &denom));
+        REFUNDS_OWED.update(deps.storage, &denom, |owed| -> StdResult<_> {
+            Ok(owed.unwrap_or_default() - amount)
+        })?;
+        claimed.push(Coin { denom, amount: Uint128::new(amount) });
+    }
+
+    Ok(Response::new()
+        .add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: claimed.clone(),
+        }))
+        .add_attribute("method", "claim_refunds")
+        .add_attribute("bidder", info.sender)
+        .add_attribute("claimed", claimed.iter().map(|coin| coin.to_string()).collect::<Vec<String>>().join(","))
+    )
+}
+
+fn validate_mint_cost(
+    mint_cost: u128,
+) -> Result<(), ContractError> {
+    if !(MIN_MINT_COST..=MAX_MINT_COST).contains(&mint_cost) {
+        return Err(ContractError::CustomError { val: format!("Mint cost must be between {} & {}", MIN_MINT_COST, MAX_MINT_COST) });
+    }
+
+    Ok(())
+}
+
+//Add funds to the reserve that pays mint fees
+fn fund_mint_reserve(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if info.funds.len() != 1 || info.funds[0].denom != MINT_DENOM || info.funds[0].amount.is_zero() {
+        return Err(ContractError::InvalidAsset { asset: format!("Mint reserve only accepts {}", MINT_DENOM) });
+    }
+    let amount = info.funds[0].amount.u128();
+
+    let mint_reserve = MINT_RESERVE.may_load(deps.storage)?.unwrap_or_default() + amount;
+    MINT_RESERVE.save(deps.storage, &mint_reserve)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "fund_mint_reserve")
+        .add_attribute("funder", info.sender)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("mint_reserve", mint_reserve.to_string())
+    )
+}
+
+//History record of the auction without a sale
+fn new_auction_record(
+    config: &Config,
+    auction: &Auction,
+) -> AuctionRecord {
+    AuctionRecord {
+        auction_id: config.current_auction_id,
+        submission_id: auction.submission_id,
+        token_id: None,
+        winner: None,
+        winning_amount: 0u128,
+        submitter: auction.submission_info.submission.submitter.clone(),
+        proceed_recipient: auction.submission_info.submission.proceed_recipient.clone(),
+        token_uri: auction.submission_info.submission.token_uri.clone(),
+        curators: auction.submission_info.curation_votes.clone(),
+        bid_count: auction.bids.len() as u64,
+        start_time: auction.auction_start_time,
+        end_time: auction.auction_end_time,
+        memecoins_distributed: 0u128,
+        cancel_reason: None,
+    }
+}
+
+//Set the live auction to the next pending auction (first in, first out)
+fn start_next_auction(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+) -> StdResult<()> {
+    let mut pending_auctions = PENDING_AUCTION.load(storage)?;
+    if !pending_auctions.is_empty() {
+        let mut next_auction = pending_auctions.remove(0);
+        PENDING_AUCTION.save(storage, &pending_auctions)?;
+        //set auction start & end time
+        next_auction.auction_start_time = env.block.time.seconds();
         next_auction.auction_end_time = env.block.time.seconds() + (SECONDS_PER_DAY * config.auction_period);
+        next_auction.total_extension = 0;
         //Save as live auction
-        AUCTION.save(deps.storage, &next_auction)?;
+        AUCTION.save(storage, &next_auction)?;
+    } else {
+        //Remove the finished auction
+        AUCTION.remove(storage);
+    }
+
+    Ok(())
+}
+
+fn cancel_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    reason: String,
+    return_to_curation: bool,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
     }
-    //Remove the concluded auction
-    AUCTION.remove(deps.storage);    
+    if reason.is_empty() {
+        return Err(ContractError::CustomError { val: "Cancellation reason can't be empty".to_string() });
+    }
+    let live_auction = AUCTION.may_load(deps.storage)?
+        .ok_or_else(|| ContractError::CustomError { val: "No live auction".to_string() })?;
+
+    //Refund the escrowed highest bid
+    if live_auction.highest_bid.amount > 0 {
+        credit_refund(deps.storage, &live_auction.highest_bid.bidder, &config.bid_denom, live_auction.highest_bid.amount)?;
+    }
+
+    //Restart curation under a new submission ID, the cancelled auction's bids & votes stay with the old one
+    let mut resubmission_id = None;
+    if return_to_curation {
+        if config.submission_total >= config.submission_limit {
+            return Err(ContractError::CustomError { val: "Exceeded submission limit, can't return the submission to curation".to_string() });
+        }
+        let submission_id = get_next_submission_id(deps.storage, &mut config)?;
+        submissions().save(deps.storage, submission_id, &SubmissionItem {
+            curation_votes: vec![],
+            curation_power: 0,
+            submission_height: env.block.height,
+            submission_end_time: env.block.time.seconds() + (config.submission_vote_period * SECONDS_PER_DAY),
+            ..live_auction.submission_info.clone()
+        })?;
+        resubmission_id = Some(submission_id);
+    } else {
+        //The submission is finalized, its fee is kept by the treasury
+        release_submission_fee(deps.storage, &live_auction.submission_info, false)?;
+    }
+
+    //Archive the cancelled auction
+    let mut record = new_auction_record(&config, &live_auction);
+    record.cancel_reason = Some(reason.clone());
+    auction_history().save(deps.storage, record.auction_id, &record)?;
+    config.current_auction_id += 1;
+    CONFIG.save(deps.storage, &config)?;
+
+    start_next_auction(deps.storage, &env, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "cancel_auction")
+        .add_attribute("auction_id", record.auction_id.to_string())
+        .add_attribute("submission_id", live_auction.submission_id.to_string())
+        .add_attribute("refunded_bidder", if live_auction.highest_bid.amount > 0 { live_auction.highest_bid.bidder.to_string() } else { String::from("none") })
+        .add_attribute("refunded_bid", live_auction.highest_bid.amount.to_string())
+        .add_attribute("return_to_curation", return_to_curation.to_string())
+        .add_attribute("resubmission_id", resubmission_id.map(|id| id.to_string()).unwrap_or_else(|| String::from("none")))
+        .add_attribute("reason", reason)
+    )
+}
+
+fn migrate_minter(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_address: String,
+    old_minter_successor: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    //The live auction's winner would be minted from the new minter
+    if let Some(live_auction) = AUCTION.may_load(deps.storage)? {
+        if live_auction.highest_bid.amount > 0 {
+            return Err(ContractError::CustomError { val: "Can't migrate the minter while the live auction has bids".to_string() });
+        }
+        //An ended auction must be settled under the minter it ran with
+        if env.block.time.seconds() >= live_auction.auction_end_time {
+            return Err(ContractError::CustomError { val: "Conclude the ended auction before migrating the minter".to_string() });
+        }
+    }
+
+    //Query the new minter's collection
+    let new_minter = deps.api.addr_validate(&new_address)?;
+    let minter_config: MinterConfigResponse = deps.querier.query_wasm_smart(new_minter.to_string(), &BaseMinterQueryMsg::Config {})
+        .map_err(|_| ContractError::CustomError { val: "Failed to query the new minter's config".to_string() })?;
+    let new_collection = deps.api.addr_validate(&minter_config.collection_address)?;
+
+    //This contract must be the collection creator to mint
+    let collection_info: CollectionInfoResponse = deps.querier.query_wasm_smart(new_collection.to_string(), &Sg721QueryMsg::CollectionInfo {})?;
+    if env.contract.address != collection_info.creator {
+        return Err(ContractError::CustomError { val: "This contract isn't the new collection's creator".to_string() });
+    }
+
+    //The next token ID follows the new minter's token index, which is unset before its first mint
+    let token_index: u64 = match deps.querier.query_wasm_raw(new_minter.to_string(), MINTER_TOKEN_INDEX.as_slice())? {
+        Some(raw) => from_json(raw)?,
+        None => 0,
+    };
+    config.current_token_id = token_index + 1;
+
+    //Hand the old minter's admin rights to the successor
+    if let Some(successor) = old_minter_successor.clone() {
+        //Only the old minter's wasm admin can change it
+        let minter_info = deps.querier.query_wasm_contract_info(config.minter_addr.clone())?;
+        if minter_info.admin != Some(env.contract.address.to_string()) {
+            return Err(ContractError::CustomError { val: "This contract isn't the old minter's admin, migrate without a successor".to_string() });
+        }
+        msgs.push(CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+            contract_addr: config.minter_addr.clone(),
+            admin: deps.api.addr_validate(&successor)?.to_string(),
+        }));
+    }
+
+    let old_minter = config.minter_addr;
+    let old_collection = config.collection_addr;
+    config.minter_addr = new_minter.to_string();
+    config.collection_addr = new_collection.to_string();
+    CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::new()
         .add_messages(msgs)
-        .add_attribute("method", "conclude_auction")
-        .add_attribute("highest_bidder", live_auction.highest_bid.bidder)
-        .add_attribute("highest_bid", live_auction.highest_bid.amount.to_string())
+        .add_attribute("method", "migrate_minter")
+        .add_attribute("minter_addr", format!("{} -> {}", old_minter, config.minter_addr))
+        .add_attribute("collection_addr", format!("{} -> {}", old_collection, config.collection_addr))
+        .add_attribute("current_token_id", config.current_token_id.to_string())
+        .add_attribute("old_minter_successor", format!("{:?}", old_minter_successor))
+    )
+}
+
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    update: UpdateConfig,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    //Log old & new values of each updated field
+    let mut attrs = vec![attr("method", "update_config")];
+
+    if let Some(bid_denom) = update.bid_denom {
+        if bid_denom.is_empty() {
+            return Err(ContractError::CustomError { val: "Bid denom can't be empty".to_string() });
+        }
+        //Escrowed bids are in the old denom
+        if let Some(live_auction) = AUCTION.may_load(deps.storage)? {
+            if live_auction.highest_bid.amount > 0 {
+                return Err(ContractError::CustomError { val: "Can't change the bid denom while the live auction has bids".to_string() });
+            }
+        }
+        //Unclaimed artist royalties are in the old denom
+        if ROYALTY_STATE.load(deps.storage)?.artist_owed > 0 {
+            return Err(ContractError::CustomError { val: "Can't change the bid denom while artist royalties are unclaimed".to_string() });
+        }
+        attrs.push(attr("bid_denom", format!("{} -> {}", config.bid_denom, bid_denom)));
+        config.bid_denom = bid_denom;
+    }
+    if let Some(memecoin_denom) = update.memecoin_denom {
+        if memecoin_denom.is_empty() {
+            return Err(ContractError::CustomError { val: "Memecoin denom can't be empty".to_string() });
+        }
+        attrs.push(attr("memecoin_denom", format!("{:?} -> {}", config.memecoin_denom, memecoin_denom)));
+        config.memecoin_denom = Some(memecoin_denom);
+    }
+    if let Some(memecoin_distribution_amount) = update.memecoin_distribution_amount {
+        if !(MIN_MEMECOIN_DISTRIBUTION_AMOUNT..=MAX_MEMECOIN_DISTRIBUTION_AMOUNT).contains(&memecoin_distribution_amount) {
+            return Err(ContractError::CustomError { val: format!("Memecoin distribution amount must be between {} & {}", MIN_MEMECOIN_DISTRIBUTION_AMOUNT, MAX_MEMECOIN_DISTRIBUTION_AMOUNT) });
+        }
+        attrs.push(attr("memecoin_distribution_amount", format!("{} -> {}", config.memecoin_distribution_amount, memecoin_distribution_amount)));
+        config.memecoin_distribution_amount = memecoin_distribution_amount;
+    }
+    if let Some(mint_cost) = update.mint_cost {
+        validate_mint_cost(mint_cost)?;
+        attrs.push(attr("mint_cost", format!("{} -> {}", config.mint_cost, mint_cost)));
+        config.mint_cost = mint_cost;
+    }
+    if let Some(submission_cost) = update.submission_cost {
+        if !(MIN_SUBMISSION_COST..=MAX_SUBMISSION_COST).contains(&submission_cost) {
+            return Err(ContractError::CustomError { val: format!("Submission cost must be between {} & {}", MIN_SUBMISSION_COST, MAX_SUBMISSION_COST) });
+        }
+        attrs.push(attr("submission_cost", format!("{} -> {}", config.submission_cost, submission_cost)));
+        config.submission_cost = submission_cost;
+    }
+    if let Some(submission_limit) = update.submission_limit {
+        if submission_limit == 0 || submission_limit < config.submission_total {
+            return Err(ContractError::CustomError { val: format!("Submission limit must be above 0 & at least the current submission total: {}", config.submission_total) });
+        }
+        attrs.push(attr("submission_limit", format!("{} -> {}", config.submission_limit, submission_limit)));
+        config.submission_limit = submission_limit;
+    }
+    if let Some(submission_vote_period) = update.submission_vote_period {
+        if !(MIN_PERIOD..=MAX_VOTE_PERIOD).contains(&submission_vote_period) {
+            return Err(ContractError::CustomError { val: format!("Submission vote period must be between {} & {} days", MIN_PERIOD, MAX_VOTE_PERIOD) });
+        }
+        attrs.push(attr("submission_vote_period", format!("{} -> {}", config.submission_vote_period, submission_vote_period)));
+        config.submission_vote_period = submission_vote_period;
+    }
+    if let Some(curation_threshold) = update.curation_threshold {
+        if curation_threshold.is_zero() || curation_threshold > Decimal::one() {
+            return Err(ContractError::CustomError { val: "Curation threshold must be above 0 & at most 1".to_string() });
+        }
+        attrs.push(attr("curation_threshold", format!("{} -> {}", config.curation_threshold, curation_threshold)));
+        config.curation_threshold = curation_threshold;
+    }
+    if let Some(auction_period) = update.auction_period {
+        if !(MIN_PERIOD..=MAX_AUCTION_PERIOD).contains(&auction_period) {
+            return Err(ContractError::CustomError { val: format!("Auction period must be between {} & {} days", MIN_PERIOD, MAX_AUCTION_PERIOD) });
+        }
+        attrs.push(attr("auction_period", format!("{} -> {}", config.auction_period, auction_period)));
+        config.auction_period = auction_period;
+    }
+    if let Some(min_bid_increment) = update.min_bid_increment {
+        match min_bid_increment {
+            BidIncrement::Absolute(amount) => if amount == 0 {
+                return Err(ContractError::CustomError { val: "Absolute bid increment must be at least 1".to_string() });
+            },
+            BidIncrement::Percent(percent) => if percent.is_zero() || percent > Decimal::one() {
+                return Err(ContractError::CustomError { val: "Percent bid increment must be above 0 & at most 1".to_string() });
+            },
+        }
+        attrs.push(attr("min_bid_increment", format!("{:?} -> {:?}", config.min_bid_increment, min_bid_increment)));
+        config.min_bid_increment = min_bid_increment;
+    }
+    if let Some(extension_window) = update.extension_window {
+        if extension_window > SECONDS_PER_DAY {
+            return Err(ContractError::CustomError { val: "Extension window can't be above 1 day".to_string() });
+        }
+        attrs.push(attr("extension_window", format!("{} -> {}", config.extension_window, extension_window)));
+        config.extension_window = extension_window;
+    }
+    if let Some(extension_length) = update.extension_length {
+        if extension_length > SECONDS_PER_DAY {
+            return Err(ContractError::CustomError { val: "Extension length can't be above 1 day".to_string() });
+        }
+        attrs.push(attr("extension_length", format!("{} -> {}", config.extension_length, extension_length)));
+        config.extension_length = extension_length;
+    }
+    if let Some(max_extension) = update.max_extension {
+        if max_extension > SECONDS_PER_DAY {
+            return Err(ContractError::CustomError { val: "Max extension can't be above 1 day".to_string() });
+        }
+        attrs.push(attr("max_extension", format!("{} -> {}", config.max_extension, max_extension)));
+        config.max_extension = max_extension;
+    }
+    if let Some(royalty_split) = update.royalty_split {
+        let total_share = royalty_split.sinks.iter()
+            .fold(royalty_split.artist_share, |total, sink| total + sink.share);
+        if total_share > Decimal::one() {
+            return Err(ContractError::CustomError { val: "Artist & sink royalty shares can't total above 1".to_string() });
+        }
+        let royalty_split = RoyaltySplit {
+            artist_share: royalty_split.artist_share,
+            sinks: royalty_split.sinks.into_iter()
+                .map(|sink| Ok(RoyaltySink { address: deps.api.addr_validate(sink.address.as_str())?, share: sink.share }))
+                .collect::<StdResult<Vec<RoyaltySink>>>()?,
+        };
+        attrs.push(attr("royalty_split", format!("{:?} -> {:?}", config.royalty_split, royalty_split)));
+        config.royalty_split = royalty_split;
+    }
+    if let Some(voting_power_source) = update.voting_power_source {
+        let voting_power_source = deps.api.addr_validate(&voting_power_source)?;
+        //Make sure it answers voting power queries
+        deps.querier.query_wasm_smart::<TotalPowerAtHeightResponse>(
+            voting_power_source.clone(),
+            &VotingPowerQueryMsg::TotalPowerAtHeight { height: None },
+        ).map_err(|_| ContractError::CustomError { val: "Voting power source must be a dao-voting-cw721-staked contract".to_string() })?;
+
+        attrs.push(attr("voting_power_source", format!("{:?} -> {}", config.voting_power_source, voting_power_source)));
+        config.voting_power_source = Some(voting_power_source);
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_owner: String,
+    expiry: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    //Replaces any pending transfer
+    let transfer = OwnershipTransfer {
+        new_owner: deps.api.addr_validate(&new_owner)?,
+        expiry: expiry.map(|seconds| env.block.time.seconds() + seconds),
+    };
+    OWNERSHIP_TRANSFER.save(deps.storage, &transfer)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "propose_new_owner")
+        .add_attribute("owner", config.owner)
+        .add_attribute("new_owner", transfer.new_owner)
+        .add_attribute("expiry", format!("{:?}", transfer.expiry))
+    )
+}
+
+fn accept_ownership(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let transfer = OWNERSHIP_TRANSFER.may_load(deps.storage)?
+        .ok_or_else(|| ContractError::CustomError { val: "No pending ownership transfer".to_string() })?;
+
+    //Only the proposed owner can accept
+    if info.sender != transfer.new_owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if let Some(expiry) = transfer.expiry {
+        if env.block.time.seconds() > expiry {
+            return Err(ContractError::CustomError { val: "Ownership transfer has expired".to_string() });
+        }
+    }
+
+    let previous_owner = config.owner;
+    config.owner = transfer.new_owner;
+    CONFIG.save(deps.storage, &config)?;
+    OWNERSHIP_TRANSFER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("method", "accept_ownership")
+        .add_attribute("previous_owner", previous_owner)
+        .add_attribute("owner", config.owner)
+    )
+}
+
+fn cancel_ownership_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    OWNERSHIP_TRANSFER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("method", "cancel_ownership_transfer")
     )
 }
 
+fn update_moderators(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for moderator in add {
+        let moderator = deps.api.addr_validate(&moderator)?;
+        if !config.moderators.contains(&moderator) {
+            config.moderators.push(moderator);
+        }
+    }
+    for moderator in remove {
+        let moderator = deps.api.addr_validate(&moderator)?;
+        config.moderators.retain(|addr| *addr != moderator);
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_moderators")
+        .add_attribute("moderators", format!("{:?}", config.moderators))
+    )
+}
+
+fn take_down_submission(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    submission_id: u64,
+    reason: String,
+    refund_fee: bool,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+
+    //Assert Authority
+    if info.sender != config.owner && !config.moderators.contains(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    if reason.is_empty() {
+        return Err(ContractError::CustomError { val: "Takedown reason can't be empty".to_string() });
+    }
+
+    //Remove the submission from curation or the pending auction queue
+    let submission = if let Some(submission) = submissions().may_load(deps.storage, submission_id)? {
+        submissions().remove(deps.storage, submission_id)?;
+        //Subtract from the submission total
+        config.submission_total -= 1;
+        CONFIG.save(deps.storage, &config)?;
+
+        submission
+    } else {
+        let mut pending_auctions = PENDING_AUCTION.load(deps.storage)?;
+        let position = pending_auctions.iter()
+            .position(|auction| auction.submission_id == submission_id)
+            .ok_or_else(|| ContractError::CustomError { val: format!("Submission {} isn't in curation or pending auction", submission_id) })?;
+        let auction = pending_auctions.remove(position);
+        PENDING_AUCTION.save(deps.storage, &pending_auctions)?;
+
+        auction.submission_info
+    };
+
+    //Refund the held submission fee, or credit it to the treasury
+    let refunded_fee = release_submission_fee(deps.storage, &submission, refund_fee)?;
+    if let Some(fee) = refunded_fee.clone() {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: submission.submission.submitter.to_string(),
+            amount: vec![fee],
+        }));
+    }
+
+    let takedown = Takedown {
+        submission_id,
+        submission: submission.submission,
+        reason,
+        moderator: info.sender,
+        refunded_fee,
+        time: env.block.time.seconds(),
+    };
+    TAKEDOWNS.save(deps.storage, submission_id, &takedown)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("method", "take_down_submission")
+        .add_attribute("submission_id", submission_id.to_string())
+        .add_attribute("moderator", takedown.moderator)
+        .add_attribute("reason", takedown.reason)
+        .add_attribute("refunded_fee", format!("{:?}", takedown.refunded_fee))
+    )
+}
+
+fn assert_not_paused(
+    storage: &dyn Storage,
+    is_paused: fn(&PauseState) -> bool,
+    action: &str,
+) -> Result<(), ContractError> {
+    if is_paused(&PAUSE_STATE.load(storage)?) {
+        return Err(ContractError::Paused { action: action.to_string() });
+    }
+
+    Ok(())
+}
+
+fn update_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    //Assert Authority
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let previous_guardian = config.guardian;
+    config.guardian = guardian.map(|guardian| deps.api.addr_validate(&guardian)).transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_guardian")
+        .add_attribute("guardian", format!("{:?} -> {:?}", previous_guardian, config.guardian))
+    )
+}
+
+fn update_pause_state(
+    deps: DepsMut,
+    info: MessageInfo,
+    update: UpdatePauseState,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut pause_state = PAUSE_STATE.load(deps.storage)?;
+
+    //Assert Authority, the guardian can only pause
+    let is_owner = info.sender == config.owner;
+    let is_guardian = config.guardian.as_ref() == Some(&info.sender);
+    let unpausing = [update.submissions, update.curation, update.bidding, update.settlement]
+        .contains(&Some(false));
+    if !is_owner && (!is_guardian || unpausing) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    //Log old & new values of each updated switch
+    let mut attrs = vec![attr("method", "update_pause_state")];
+
+    if let Some(submissions) = update.submissions {
+        attrs.push(attr("submissions", format!("{} -> {}", pause_state.submissions, submissions)));
+        pause_state.submissions = submissions;
+    }
+    if let Some(curation) = update.curation {
+        attrs.push(attr("curation", format!("{} -> {}", pause_state.curation, curation)));
+        pause_state.curation = curation;
+    }
+    if let Some(bidding) = update.bidding {
+        attrs.push(attr("bidding", format!("{} -> {}", pause_state.bidding, bidding)));
+        pause_state.bidding = bidding;
+    }
+    if let Some(settlement) = update.settlement {
+        attrs.push(attr("settlement", format!("{} -> {}", pause_state.settlement, settlement)));
+        pause_state.settlement = settlement;
+    }
+
+    PAUSE_STATE.save(deps.storage, &pause_state)?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Submissions { limit, start_after } => to_json_binary(&query_submissions(deps, env, limit, start_after)?),
+        QueryMsg::LiveAuction {} => to_json_binary(&query_live_auction(deps, env)?),
+        QueryMsg::PendingAuctions { limit, start_after } => to_json_binary(&query_pending_auctions(deps, env, limit, start_after)?),
+        QueryMsg::PendingAuctionPosition { submission_id } => to_json_binary(&query_pending_auction_position(deps, env, submission_id)?),
+        QueryMsg::PendingAuctionsBySubmitter { submitter, limit, start_after } => to_json_binary(&query_pending_auctions_by_submitter(deps, env, submitter, limit, start_after)?),
+        QueryMsg::PendingAuctionsByRecipient { recipient, limit, start_after } => to_json_binary(&query_pending_auctions_by_recipient(deps, env, recipient, limit, start_after)?),
+        QueryMsg::SubmissionsBySubmitter { submitter, limit, start_after } => to_json_binary(&query_submissions_by_submitter(deps, submitter, limit, start_after)?),
+        QueryMsg::SubmissionsByRecipient { recipient, limit, start_after } => to_json_binary(&query_submissions_by_recipient(deps, recipient, limit, start_after)?),
+        QueryMsg::BidsByBidder { bidder, limit, start_after } => to_json_binary(&query_bids_by_bidder(deps, bidder, limit, start_after)?),
+        QueryMsg::VotesByCurator { curator, limit, start_after } => to_json_binary(&query_votes_by_curator(deps, curator, limit, start_after)?),
+        QueryMsg::AuctionHistory { limit, start_after } => to_json_binary(&query_auction_history(deps, limit, start_after)?),
+        QueryMsg::AuctionByTokenId { token_id } => to_json_binary(&query_auction_by_token_id(deps, token_id)?),
+        QueryMsg::AuctionsByWinner { winner, limit, start_after } => to_json_binary(&query_auctions_by_winner(deps, winner, limit, start_after)?),
+        QueryMsg::AuctionsByArtist { artist, limit, start_after } => to_json_binary(&query_auctions_by_artist(deps, artist, limit, start_after)?),
+        QueryMsg::AuctionsBySubmitter { submitter, limit, start_after } => to_json_binary(&query_auctions_by_submitter(deps, submitter, limit, start_after)?),
+        QueryMsg::OwnershipTransfer {} => to_json_binary(&query_ownership_transfer(deps)?),
+        QueryMsg::Refunds { address } => to_json_binary(&query_refunds(deps, address)?),
+        QueryMsg::Takedown { submission_id } => to_json_binary(&query_takedown(deps, submission_id)?),
+        QueryMsg::Takedowns { limit, start_after } => to_json_binary(&query_takedowns(deps, limit, start_after)?),
+        QueryMsg::ArtistRoyalties { artist } => to_json_binary(&query_artist_royalties(deps, artist)?),
+        QueryMsg::Treasury {} => to_json_binary(&query_treasury(deps)?),
+        QueryMsg::MintReserve {} => to_json_binary(&query_mint_reserve(deps)?),
+        QueryMsg::PauseState {} => to_json_binary(&query_pause_state(deps)?),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
     match msg.id {
@@ -534,3 +1833,33 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
         id => Err(StdError::generic_err(format!("invalid reply id: {}", id))),
     }
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let stored = get_contract_version(deps.storage)?;
+
+    //Only migrate from this contract
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::CustomError { val: format!("Can't migrate from {}", stored.contract) });
+    }
+    let stored_version: Version = stored.version.parse()
+        .map_err(|_| ContractError::CustomError { val: format!("Invalid stored version: {}", stored.version) })?;
+    let new_version: Version = CONTRACT_VERSION.parse()
+        .map_err(|_| ContractError::CustomError { val: format!("Invalid contract version: {}", CONTRACT_VERSION) })?;
+    if stored_version > new_version {
+        return Err(ContractError::CustomError { val: format!("Can't downgrade from {} to {}", stored_version, new_version) });
+    }
+
+    //Run each release's state migrations in order
+    if stored_version < Version::new(0, 2, 0) {
+        migrate_from_v0_1(deps.branch(), env.block.height)?;
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("previous_version", stored.version)
+        .add_attribute("new_version", CONTRACT_VERSION)
+    )
+}
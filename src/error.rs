@@ -19,6 +19,9 @@ pub enum ContractError {
 
     #[error("InvalidTokenURI: {uri}")]
     InvalidTokenURI { uri: String },
+
+    #[error("Paused: {action}")]
+    Paused { action: String },
 }
 
 impl From<OverflowError> for ContractError {
@@ -1,22 +1,26 @@
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use cosmwasm_std::{to_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, QuerierWrapper, StdResult, Uint128, WasmMsg};
 
-use membrane::liquidity_check::ExecuteMsg;
+use crate::msgs::{
+    ArtistRoyaltiesResponse, AuctionHistoryResponse, BidderBidsResponse, Config, CuratorVotesResponse, ExecuteMsg, LiveAuctionResponse,
+    PendingAuctionResponse, PendingAuctionsResponse, QueryMsg, RefundsResponse, SubmissionsResponse, TakedownsResponse, TreasuryResponse, UpdateConfig, UpdatePauseState,
+};
+use crate::state::{AuctionRecord, OwnershipTransfer, PauseState, Takedown};
 
-/// LiquidityContract is a wrapper around Addr that provides a lot of helpers
+/// BraneAuctionContract is a wrapper around Addr that provides a lot of helpers
 /// for working with this.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-pub struct LiquidityContract(pub Addr);
+pub struct BraneAuctionContract(pub Addr);
 
-impl LiquidityContract {
+impl BraneAuctionContract {
     pub fn addr(&self) -> Addr {
         self.0.clone()
     }
 
     pub fn call<T: Into<ExecuteMsg>>(&self, msg: T, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
-        let msg = to_binary(&msg.into())?;
+        let msg = to_json_binary(&msg.into())?;
         Ok(WasmMsg::Execute {
             contract_addr: self.addr().into(),
             msg,
@@ -24,4 +28,206 @@ impl LiquidityContract {
         }
         .into())
     }
+
+    /// Submit an NFT for curation. Collection holders submit for free,
+    /// everyone else pays the config's submission cost in the bid_denom.
+    pub fn submit_nft(
+        &self,
+        config: &Config,
+        submitter: String,
+        proceed_recipient: String,
+        token_uri: String,
+        reserve_price: Option<u128>,
+        is_holder: bool,
+    ) -> StdResult<CosmosMsg> {
+        let funds = if is_holder {
+            vec![]
+        } else {
+            vec![Coin {
+                denom: config.bid_denom.clone(),
+                amount: Uint128::new(config.submission_cost),
+            }]
+        };
+
+        self.call(ExecuteMsg::SubmitNFT { submitter, proceed_recipient, token_uri, reserve_price }, funds)
+    }
+
+    pub fn vote_to_curate(&self, submission_ids: Vec<u64>, vote: bool) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::VoteToCurate { submission_ids, vote }, vec![])
+    }
+
+    /// Bid on the live auction with the amount in the config's bid_denom
+    pub fn bid(&self, config: &Config, amount: u128) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::Bid {},
+            vec![Coin {
+                denom: config.bid_denom.clone(),
+                amount: Uint128::new(amount),
+            }],
+        )
+    }
+
+    pub fn conclude_auction(&self) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::ConcludeAuction {}, vec![])
+    }
+
+    pub fn distribute_royalties(&self, denom: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::DistributeRoyalties { denom }, vec![])
+    }
+
+    pub fn claim_royalties(&self) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::ClaimRoyalties {}, vec![])
+    }
+
+    pub fn claim_refunds(&self) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::ClaimRefunds {}, vec![])
+    }
+
+    pub fn fund_mint_reserve(&self, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::FundMintReserve {}, funds)
+    }
+
+    pub fn cancel_auction(&self, reason: String, return_to_curation: bool) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::CancelAuction { reason, return_to_curation }, vec![])
+    }
+
+    pub fn migrate_minter(&self, new_address: String, old_minter_successor: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::MigrateMinter { new_address, old_minter_successor }, vec![])
+    }
+
+    pub fn update_config(&self, update: UpdateConfig) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateConfig(update), vec![])
+    }
+
+    pub fn propose_new_owner(&self, new_owner: String, expiry: Option<u64>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::ProposeNewOwner { new_owner, expiry }, vec![])
+    }
+
+    pub fn accept_ownership(&self) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::AcceptOwnership {}, vec![])
+    }
+
+    pub fn cancel_ownership_transfer(&self) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::CancelOwnershipTransfer {}, vec![])
+    }
+
+    pub fn update_moderators(&self, add: Vec<String>, remove: Vec<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateModerators { add, remove }, vec![])
+    }
+
+    pub fn take_down_submission(&self, submission_id: u64, reason: String, refund_fee: bool) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::TakeDownSubmission { submission_id, reason, refund_fee }, vec![])
+    }
+
+    pub fn withdraw_treasury(&self, denom: String, amount: Option<u128>, recipient: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::WithdrawTreasury { denom, amount, recipient }, vec![])
+    }
+
+    pub fn update_guardian(&self, guardian: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateGuardian { guardian }, vec![])
+    }
+
+    pub fn update_pause_state(&self, update: UpdatePauseState) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdatePauseState(update), vec![])
+    }
+
+    fn query<T: DeserializeOwned>(&self, querier: &QuerierWrapper, msg: &QueryMsg) -> StdResult<T> {
+        querier.query_wasm_smart(self.addr(), msg)
+    }
+
+    pub fn config(&self, querier: &QuerierWrapper) -> StdResult<Config> {
+        self.query(querier, &QueryMsg::Config {})
+    }
+
+    pub fn submissions(&self, querier: &QuerierWrapper, limit: Option<u32>, start_after: Option<u64>) -> StdResult<SubmissionsResponse> {
+        self.query(querier, &QueryMsg::Submissions { limit, start_after })
+    }
+
+    pub fn live_auction(&self, querier: &QuerierWrapper) -> StdResult<LiveAuctionResponse> {
+        self.query(querier, &QueryMsg::LiveAuction {})
+    }
+
+    pub fn pending_auctions(&self, querier: &QuerierWrapper, limit: Option<u32>, start_after: Option<u32>) -> StdResult<PendingAuctionsResponse> {
+        self.query(querier, &QueryMsg::PendingAuctions { limit, start_after })
+    }
+
+    pub fn pending_auction_position(&self, querier: &QuerierWrapper, submission_id: u64) -> StdResult<PendingAuctionResponse> {
+        self.query(querier, &QueryMsg::PendingAuctionPosition { submission_id })
+    }
+
+    pub fn pending_auctions_by_submitter(&self, querier: &QuerierWrapper, submitter: String, limit: Option<u32>, start_after: Option<u32>) -> StdResult<PendingAuctionsResponse> {
+        self.query(querier, &QueryMsg::PendingAuctionsBySubmitter { submitter, limit, start_after })
+    }
+
+    pub fn pending_auctions_by_recipient(&self, querier: &QuerierWrapper, recipient: String, limit: Option<u32>, start_after: Option<u32>) -> StdResult<PendingAuctionsResponse> {
+        self.query(querier, &QueryMsg::PendingAuctionsByRecipient { recipient, limit, start_after })
+    }
+
+    pub fn submissions_by_submitter(&self, querier: &QuerierWrapper, submitter: String, limit: Option<u32>, start_after: Option<u64>) -> StdResult<SubmissionsResponse> {
+        self.query(querier, &QueryMsg::SubmissionsBySubmitter { submitter, limit, start_after })
+    }
+
+    pub fn submissions_by_recipient(&self, querier: &QuerierWrapper, recipient: String, limit: Option<u32>, start_after: Option<u64>) -> StdResult<SubmissionsResponse> {
+        self.query(querier, &QueryMsg::SubmissionsByRecipient { recipient, limit, start_after })
+    }
+
+    pub fn bids_by_bidder(&self, querier: &QuerierWrapper, bidder: String, limit: Option<u32>, start_after: Option<u64>) -> StdResult<BidderBidsResponse> {
+        self.query(querier, &QueryMsg::BidsByBidder { bidder, limit, start_after })
+    }
+
+    pub fn votes_by_curator(&self, querier: &QuerierWrapper, curator: String, limit: Option<u32>, start_after: Option<u64>) -> StdResult<CuratorVotesResponse> {
+        self.query(querier, &QueryMsg::VotesByCurator { curator, limit, start_after })
+    }
+
+    pub fn auction_history(&self, querier: &QuerierWrapper, limit: Option<u32>, start_after: Option<u64>) -> StdResult<AuctionHistoryResponse> {
+        self.query(querier, &QueryMsg::AuctionHistory { limit, start_after })
+    }
+
+    pub fn auction_by_token_id(&self, querier: &QuerierWrapper, token_id: String) -> StdResult<AuctionRecord> {
+        self.query(querier, &QueryMsg::AuctionByTokenId { token_id })
+    }
+
+    pub fn auctions_by_winner(&self, querier: &QuerierWrapper, winner: String, limit: Option<u32>, start_after: Option<u64>) -> StdResult<AuctionHistoryResponse> {
+        self.query(querier, &QueryMsg::AuctionsByWinner { winner, limit, start_after })
+    }
+
+    pub fn auctions_by_artist(&self, querier: &QuerierWrapper, artist: String, limit: Option<u32>, start_after: Option<u64>) -> StdResult<AuctionHistoryResponse> {
+        self.query(querier, &QueryMsg::AuctionsByArtist { artist, limit, start_after })
+    }
+
+    pub fn auctions_by_submitter(&self, querier: &QuerierWrapper, submitter: String, limit: Option<u32>, start_after: Option<u64>) -> StdResult<AuctionHistoryResponse> {
+        self.query(querier, &QueryMsg::AuctionsBySubmitter { submitter, limit, start_after })
+    }
+
+    pub fn ownership_transfer(&self, querier: &QuerierWrapper) -> StdResult<Option<OwnershipTransfer>> {
+        self.query(querier, &QueryMsg::OwnershipTransfer {})
+    }
+
+    pub fn takedown(&self, querier: &QuerierWrapper, submission_id: u64) -> StdResult<Takedown> {
+        self.query(querier, &QueryMsg::Takedown { submission_id })
+    }
+
+    pub fn takedowns(&self, querier: &QuerierWrapper, limit: Option<u32>, start_after: Option<u64>) -> StdResult<TakedownsResponse> {
+        self.query(querier, &QueryMsg::Takedowns { limit, start_after })
+    }
+
+    pub fn artist_royalties(&self, querier: &QuerierWrapper, artist: String) -> StdResult<ArtistRoyaltiesResponse> {
+        self.query(querier, &QueryMsg::ArtistRoyalties { artist })
+    }
+
+    pub fn refunds(&self, querier: &QuerierWrapper, address: String) -> StdResult<RefundsResponse> {
+        self.query(querier, &QueryMsg::Refunds { address })
+    }
+
+    pub fn treasury(&self, querier: &QuerierWrapper) -> StdResult<TreasuryResponse> {
+        self.query(querier, &QueryMsg::Treasury {})
+    }
+
+    pub fn mint_reserve(&self, querier: &QuerierWrapper) -> StdResult<Coin> {
+        self.query(querier, &QueryMsg::MintReserve {})
+    }
+
+    pub fn pause_state(&self, querier: &QuerierWrapper) -> StdResult<PauseState> {
+        self.query(querier, &QueryMsg::PauseState {})
+    }
 }
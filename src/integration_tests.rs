@@ -2,71 +2,354 @@
 #[allow(unused_variables)]
 mod tests {
 
-    use crate::helpers::LiquidityContract;
-
-    use membrane::liquidity_check::{ExecuteMsg, InstantiateMsg, QueryMsg};
-    use membrane::types::{AssetInfo, PoolType};
+    use crate::helpers::BraneAuctionContract;
+    use crate::msgs::{BidIncrement, CollectionParams as CollectionParamsMsg, ExecuteMsg, FirstSubmission, InstantiateMsg, TotalPowerAtHeightResponse, UpdateConfig, UpdatePauseState, VotingPowerAtHeightResponse, VotingPowerQueryMsg};
 
     use cosmwasm_std::{
-        coin, to_binary, Addr, Binary, Empty, Response, StdResult, Uint128, Decimal,
+        coin, to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Empty, Env, Reply, Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
     };
-    use cw_multi_test::{App, AppBuilder, BankKeeper, Contract, ContractWrapper, Executor};
-    use osmo_bindings::{PoolStateResponse};
+    use cw721::{NumTokensResponse, TokensResponse};
+    use cw721_base::msg::MinterResponse;
+    use cw_utils::parse_reply_instantiate_data;
+    use sg721_base::msg::CollectionInfoResponse;
+    use cw_multi_test::{App, AppBuilder, AppResponse, BankKeeper, Contract, ContractWrapper, Executor};
+    use cw_storage_plus::{Bound, Item, Map};
+    use sg2::msg::{CollectionParams, CreateMinterMsg, Sg2ExecuteMsg};
+    use sg721::CollectionInfo;
     use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
 
     const USER: &str = "user";
     const ADMIN: &str = "admin";
+    const ARTIST: &str = "artist";
+    const BIDDER_1: &str = "bidder_1";
+    const BIDDER_2: &str = "bidder_2";
+    const MEME_WHALE: &str = "meme_whale";
+
+    const NATIVE_DENOM: &str = "ustars";
+    const BID_DENOM: &str = "ubid";
+    const MEME_DENOM: &str = "umeme";
 
-    //Liquidity Contract
-    pub fn liquidity_contract() -> Box<dyn Contract<Empty>> {
+    const SECONDS_PER_DAY: u64 = 86400u64;
+    const MINT_COST: u128 = 50_000_000u128;
+    const MAINNET_MINT_COST: u128 = 5_000_000_000u128;
+    const SUBMISSION_COST: u128 = 10_000_000u128;
+    const MEMECOIN_DISTRIBUTION: u128 = 100_000_000u128;
+
+    //Brane Auction Contract
+    pub fn auction_contract() -> Box<dyn Contract<Empty>> {
         let contract = ContractWrapper::new_with_empty(
             crate::contracts::execute,
             crate::contracts::instantiate,
             crate::contracts::query,
+        )
+        .with_reply(crate::contracts::reply)
+        .with_migrate(crate::contracts::migrate);
+        Box::new(contract)
+    }
+
+    //Mock sg2 Base Factory Contract
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct Factory_MockInstantiateMsg {
+        pub minter_code_id: u64,
+    }
+
+    const FACTORY_MINTER_CODE_ID: Item<u64> = Item::new("minter_code_id");
+
+    pub fn factory_contract() -> Box<dyn Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            |deps, _, info, msg: Sg2ExecuteMsg<Option<String>>| -> StdResult<Response> {
+                match msg {
+                    Sg2ExecuteMsg::CreateMinter(mut msg) => {
+                        //The factory sets the caller as the collection creator
+                        msg.collection_params.info.creator = info.sender.to_string();
+
+                        Ok(Response::new().add_message(WasmMsg::Instantiate {
+                            admin: Some(info.sender.to_string()),
+                            code_id: FACTORY_MINTER_CODE_ID.load(deps.storage)?,
+                            msg: to_json_binary(&msg)?,
+                            funds: vec![],
+                            label: String::from("Minter"),
+                        }))
+                    }
+                }
+            },
+            |deps, _, _, msg: Factory_MockInstantiateMsg| -> StdResult<Response> {
+                FACTORY_MINTER_CODE_ID.save(deps.storage, &msg.minter_code_id)?;
+                Ok(Response::default())
+            },
+            |_, _, _: Empty| -> StdResult<Binary> {
+                Err(StdError::generic_err("no queries"))
+            },
         );
         Box::new(contract)
     }
 
-    //Mock Osmo Proxy Contract
+    //Mock Base Minter Contract
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
     #[serde(rename_all = "snake_case")]
-    pub enum Osmo_MockExecuteMsg {}
+    pub enum Minter_MockExecuteMsg {
+        Mint { token_uri: String },
+    }
 
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
     #[serde(rename_all = "snake_case")]
-    pub struct Osmo_MockInstantiateMsg {}
+    pub enum Minter_MockQueryMsg {
+        Config {},
+    }
 
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
     #[serde(rename_all = "snake_case")]
-    pub enum Osmo_MockQueryMsg {
-        PoolState {
-            id: u64,
-        }
+    pub struct Minter_MockConfigResponse {
+        pub collection_address: String,
     }
 
-    pub fn osmosis_proxy_contract() -> Box<dyn Contract<Empty>> {
+    const MINTER_CREATOR: Item<Addr> = Item::new("creator");
+    const MINTER_COLLECTION: Item<Addr> = Item::new("collection");
+    const MINTER_TOKEN_INDEX: Item<u64> = Item::new("token_index");
+
+    pub fn minter_contract() -> Box<dyn Contract<Empty>> {
         let contract = ContractWrapper::new(
-            |deps, _, info, msg: Osmo_MockExecuteMsg| -> StdResult<Response> {
-                Ok(Response::default())
+            |deps, _, info, msg: Minter_MockExecuteMsg| -> StdResult<Response> {
+                match msg {
+                    Minter_MockExecuteMsg::Mint { token_uri } => {
+                        //Only the collection creator can mint
+                        if info.sender != MINTER_CREATOR.load(deps.storage)? {
+                            return Err(StdError::generic_err("Unauthorized"));
+                        }
+                        //Token IDs start at 1
+                        let token_id = MINTER_TOKEN_INDEX.load(deps.storage)? + 1;
+                        MINTER_TOKEN_INDEX.save(deps.storage, &token_id)?;
+
+                        Ok(Response::new().add_message(WasmMsg::Execute {
+                            contract_addr: MINTER_COLLECTION.load(deps.storage)?.to_string(),
+                            msg: to_json_binary(&Collection_MockExecuteMsg::Mint {
+                                token_id: token_id.to_string(),
+                                owner: info.sender.to_string(),
+                                token_uri: Some(token_uri),
+                                extension: None,
+                            })?,
+                            funds: vec![],
+                        }))
+                    }
+                }
             },
-            |_, _, _, _: Osmo_MockInstantiateMsg| -> StdResult<Response> {
-                Ok(Response::default())
+            |deps, env, _, msg: CreateMinterMsg<Option<String>>| -> StdResult<Response> {
+                MINTER_CREATOR.save(deps.storage, &Addr::unchecked(msg.collection_params.info.creator.clone()))?;
+                MINTER_TOKEN_INDEX.save(deps.storage, &0)?;
+
+                Ok(Response::new().add_submessage(SubMsg::reply_on_success(
+                    WasmMsg::Instantiate {
+                        admin: None,
+                        code_id: msg.collection_params.code_id,
+                        msg: to_json_binary(&Collection_MockInstantiateMsg {
+                            minter: env.contract.address.to_string(),
+                            creator: msg.collection_params.info.creator,
+                        })?,
+                        funds: vec![],
+                        label: String::from("sg721"),
+                    },
+                    1,
+                )))
+            },
+            |deps: Deps, _, msg: Minter_MockQueryMsg| -> StdResult<Binary> {
+                match msg {
+                    Minter_MockQueryMsg::Config {} => to_json_binary(&Minter_MockConfigResponse {
+                        collection_address: MINTER_COLLECTION.load(deps.storage)?.to_string(),
+                    }),
+                }
             },
-            |_, _, msg: Osmo_MockQueryMsg| -> StdResult<Binary> {
+        ).with_reply(|deps: DepsMut, _: Env, msg: Reply| -> StdResult<Response> {
+            let collection_addr = parse_reply_instantiate_data(msg)
+                .map_err(|_| StdError::generic_err("missing collection address"))?
+                .contract_address;
+            MINTER_COLLECTION.save(deps.storage, &Addr::unchecked(&collection_addr))?;
+
+            Ok(Response::new()
+                .add_attribute("action", "instantiate_sg721_reply")
+                .add_attribute("sg721_address", collection_addr))
+        });
+        Box::new(contract)
+    }
+
+    //Mock sg721 Collection Contract
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct Collection_MockInstantiateMsg {
+        pub minter: String,
+        pub creator: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Collection_MockExecuteMsg {
+        Mint {
+            token_id: String,
+            owner: String,
+            token_uri: Option<String>,
+            extension: Option<Empty>,
+        },
+        TransferNft {
+            recipient: String,
+            token_id: String,
+        },
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Collection_MockQueryMsg {
+        Tokens {
+            owner: String,
+            start_after: Option<String>,
+            limit: Option<u32>,
+        },
+        AllTokens {
+            start_after: Option<String>,
+            limit: Option<u32>,
+        },
+        NumTokens {},
+        CollectionInfo {},
+        Minter {},
+    }
+
+    const COLLECTION_MINTER: Item<Addr> = Item::new("minter");
+    const COLLECTION_CREATOR: Item<Addr> = Item::new("creator");
+    const COLLECTION_TOKENS: Map<&str, Addr> = Map::new("tokens");
+
+    pub fn collection_contract() -> Box<dyn Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            |deps, _, info, msg: Collection_MockExecuteMsg| -> StdResult<Response> {
                 match msg {
-                    Osmo_MockQueryMsg::PoolState { id } => {
-                        if id == 99u64 {
-                            Ok(to_binary(&PoolStateResponse {
-                                assets: vec![coin(100_000_000, "base"), coin(100_000_000, "quote")],
-                                shares: coin(100_000_000, "lp_denom"),
-                            })?)
-                        } else {
-                            Ok(to_binary(&PoolStateResponse {
-                                assets: vec![coin(49_999, "credit_fulldenom"), coin(49_999, "fiat_coin")],
-                                shares: coin(0, "shares"),
-                            })?)
+                    Collection_MockExecuteMsg::Mint { token_id, owner, .. } => {
+                        if info.sender != COLLECTION_MINTER.load(deps.storage)? {
+                            return Err(StdError::generic_err("Unauthorized"));
+                        }
+                        if COLLECTION_TOKENS.has(deps.storage, &token_id) {
+                            return Err(StdError::generic_err("token_id already claimed"));
+                        }
+                        COLLECTION_TOKENS.save(deps.storage, &token_id, &Addr::unchecked(owner))?;
+                        Ok(Response::new().add_attribute("action", "mint").add_attribute("token_id", token_id))
+                    }
+                    Collection_MockExecuteMsg::TransferNft { recipient, token_id } => {
+                        if info.sender != COLLECTION_TOKENS.load(deps.storage, &token_id)? {
+                            return Err(StdError::generic_err("Unauthorized"));
                         }
+                        COLLECTION_TOKENS.save(deps.storage, &token_id, &Addr::unchecked(recipient))?;
+                        Ok(Response::new().add_attribute("action", "transfer_nft").add_attribute("token_id", token_id))
+                    }
+                }
+            },
+            |deps, _, _, msg: Collection_MockInstantiateMsg| -> StdResult<Response> {
+                COLLECTION_MINTER.save(deps.storage, &Addr::unchecked(msg.minter))?;
+                COLLECTION_CREATOR.save(deps.storage, &Addr::unchecked(msg.creator))?;
+                Ok(Response::default())
+            },
+            |deps: Deps, _, msg: Collection_MockQueryMsg| -> StdResult<Binary> {
+                match msg {
+                    Collection_MockQueryMsg::Tokens { owner, .. } => {
+                        let tokens = COLLECTION_TOKENS
+                            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                            .filter_map(|item| item.ok())
+                            .filter(|(_, token_owner)| token_owner.as_str() == owner)
+                            .map(|(token_id, _)| token_id)
+                            .collect::<Vec<String>>();
+                        to_json_binary(&TokensResponse { tokens })
+                    }
+                    Collection_MockQueryMsg::Minter {} => {
+                        to_json_binary(&MinterResponse {
+                            minter: Some(COLLECTION_MINTER.load(deps.storage)?.to_string()),
+                        })
+                    }
+                    Collection_MockQueryMsg::AllTokens { .. } => {
+                        let tokens = COLLECTION_TOKENS
+                            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                            .collect::<StdResult<Vec<String>>>()?;
+                        to_json_binary(&TokensResponse { tokens })
+                    }
+                    Collection_MockQueryMsg::NumTokens {} => {
+                        let count = COLLECTION_TOKENS
+                            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                            .count() as u64;
+                        to_json_binary(&NumTokensResponse { count })
+                    }
+                    Collection_MockQueryMsg::CollectionInfo {} => {
+                        to_json_binary(&CollectionInfoResponse {
+                            creator: COLLECTION_CREATOR.load(deps.storage)?.to_string(),
+                            description: String::from("mock"),
+                            image: String::from("ipfs://mock"),
+                            external_link: None,
+                            explicit_content: None,
+                            start_trading_time: None,
+                            royalty_info: None,
+                        })
+                    }
+                }
+            },
+        );
+        Box::new(contract)
+    }
+
+    //Mock dao-voting-cw721-staked Contract
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Staking_MockExecuteMsg {
+        SetPower { address: String, power: u128 },
+    }
+
+    //Powers are snapshotted by the height they were set at
+    const STAKING_POWERS: Map<(&Addr, u64), u128> = Map::new("powers");
+    const STAKING_TOTALS: Map<u64, u128> = Map::new("totals");
+
+    pub fn staking_contract() -> Box<dyn Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            |deps, env, _, msg: Staking_MockExecuteMsg| -> StdResult<Response> {
+                match msg {
+                    Staking_MockExecuteMsg::SetPower { address, power } => {
+                        let address = Addr::unchecked(address);
+                        let old_power = STAKING_POWERS
+                            .prefix(&address)
+                            .range(deps.storage, None, None, cosmwasm_std::Order::Descending)
+                            .next()
+                            .transpose()?
+                            .map(|(_, power)| power)
+                            .unwrap_or_default();
+                        let old_total = STAKING_TOTALS
+                            .range(deps.storage, None, None, cosmwasm_std::Order::Descending)
+                            .next()
+                            .transpose()?
+                            .map(|(_, total)| total)
+                            .unwrap_or_default();
+                        STAKING_POWERS.save(deps.storage, (&address, env.block.height), &power)?;
+                        STAKING_TOTALS.save(deps.storage, env.block.height, &(old_total - old_power + power))?;
+                        Ok(Response::default())
+                    }
+                }
+            },
+            |_, _, _, _: Empty| -> StdResult<Response> { Ok(Response::default()) },
+            |deps: Deps, env: Env, msg: VotingPowerQueryMsg| -> StdResult<Binary> {
+                //Like a snapshot, changes at a height are visible from the next height
+                match msg {
+                    VotingPowerQueryMsg::VotingPowerAtHeight { address, height } => {
+                        let height = height.unwrap_or(env.block.height + 1);
+                        let power = STAKING_POWERS
+                            .prefix(&Addr::unchecked(address))
+                            .range(deps.storage, None, Some(Bound::exclusive(height)), cosmwasm_std::Order::Descending)
+                            .next()
+                            .transpose()?
+                            .map(|(_, power)| power)
+                            .unwrap_or_default();
+                        to_json_binary(&VotingPowerAtHeightResponse { power: Uint128::new(power), height })
+                    }
+                    VotingPowerQueryMsg::TotalPowerAtHeight { height } => {
+                        let height = height.unwrap_or(env.block.height + 1);
+                        let power = STAKING_TOTALS
+                            .range(deps.storage, None, Some(Bound::exclusive(height)), cosmwasm_std::Order::Descending)
+                            .next()
+                            .transpose()?
+                            .map(|(_, total)| total)
+                            .unwrap_or_default();
+                        to_json_binary(&TotalPowerAtHeightResponse { power: Uint128::new(power), height })
                     }
                 }
             },
@@ -80,20 +363,32 @@ mod tests {
 
             bank.init_balance(
                 storage,
-                &Addr::unchecked("contract3"),
-                vec![coin(30_000_000_000_000, "mbrn_denom")],
+                &Addr::unchecked(ADMIN),
+                vec![coin(1_000_000_000, NATIVE_DENOM)],
+            )
+            .unwrap();
+            bank.init_balance(
+                storage,
+                &Addr::unchecked(USER),
+                vec![coin(100_000_000, BID_DENOM)],
             )
-            .unwrap(); //contract3 = Builders contract
+            .unwrap();
             bank.init_balance(
                 storage,
-                &Addr::unchecked("coin_God"),
-                vec![coin(100_000_000, "debit"), coin(100_000_000, "2nddebit")],
+                &Addr::unchecked(BIDDER_1),
+                vec![coin(1_000_000_000, BID_DENOM), coin(1_000, "not_bid_denom")],
             )
             .unwrap();
             bank.init_balance(
                 storage,
-                &Addr::unchecked(USER),
-                vec![coin(99, "error"), coin(101, "credit_fulldenom")],
+                &Addr::unchecked(BIDDER_2),
+                vec![coin(1_000_000_000, BID_DENOM)],
+            )
+            .unwrap();
+            bank.init_balance(
+                storage,
+                &Addr::unchecked(MEME_WHALE),
+                vec![coin(1_000_000_000, MEME_DENOM)],
             )
             .unwrap();
 
@@ -101,277 +396,1820 @@ mod tests {
         })
     }
 
-    fn proper_instantiate() -> (App, LiquidityContract) {
+    fn first_submission() -> FirstSubmission {
+        FirstSubmission {
+            submitter: String::from(ARTIST),
+            proceed_recipient: String::from(ARTIST),
+            token_uri: String::from("ipfs://first_submission"),
+            reserve_price: None,
+        }
+    }
+
+    fn collection_params() -> CollectionParamsMsg {
+        CollectionParamsMsg {
+            image: String::from("ipfs://collection_image"),
+            ..Default::default()
+        }
+    }
+
+    fn proper_instantiate() -> (App, BraneAuctionContract) {
         let mut app = mock_app();
+        //Seed the mint reserve
+        let auction_contract_addr = instantiate_auction(&mut app, first_submission(), MINT_COST, 500_000_000, collection_params()).unwrap();
+
+        let auction_contract = BraneAuctionContract(auction_contract_addr.clone());
 
-        //Instaniate Osmosis Proxy
-        let proxy_id = app.store_code(osmosis_proxy_contract());
+        //Fund memecoin distributions
+        app.send_tokens(Addr::unchecked(MEME_WHALE), auction_contract_addr, &[coin(1_000_000_000, MEME_DENOM)]).unwrap();
 
-        let osmosis_proxy_contract_addr = app
+        (app, auction_contract)
+    }
+
+    fn instantiate_auction(app: &mut App, first_submission: FirstSubmission, mint_cost: u128, mint_reserve: u128, collection_params: CollectionParamsMsg) -> anyhow::Result<Addr> {
+        //Store Stargaze mocks
+        let collection_id = app.store_code(collection_contract());
+        let minter_id = app.store_code(minter_contract());
+        let factory_id = app.store_code(factory_contract());
+
+        //Instantiate Base Factory
+        let factory_contract_addr = app
             .instantiate_contract(
-                proxy_id,
+                factory_id,
                 Addr::unchecked(ADMIN),
-                &Osmo_MockInstantiateMsg {},
+                &Factory_MockInstantiateMsg { minter_code_id: minter_id },
                 &[],
                 "test",
                 None,
             )
             .unwrap();
 
-        //Instantiate Liquidity contract
-        let liquidity_id = app.store_code(liquidity_contract());
+        //Instantiate Brane Auction contract w/ the minter creation fee & the mint reserve
+        let auction_id = app.store_code(auction_contract());
 
         let msg = InstantiateMsg {
-            owner: None,
-            osmosis_proxy: osmosis_proxy_contract_addr.to_string(),
-            positions_contract: "cdp".to_string(),
+            sg721_code_id: collection_id,
+            base_factory_address: factory_contract_addr.to_string(),
+            bid_denom: String::from(BID_DENOM),
+            memecoin_denom: Some(String::from(MEME_DENOM)),
+            first_submission,
+            mint_cost,
+            collection_params,
         };
 
-        let liquidity_contract_addr = app
+        app.instantiate_contract(
+            auction_id,
+            Addr::unchecked(ADMIN),
+            &msg,
+            &[coin(250_000_000 + mint_reserve, NATIVE_DENOM)],
+            "test",
+            None,
+        )
+    }
+
+    //Instantiate a base-minter & collection outside of the factory
+    fn create_minter(app: &mut App, creator: &Addr) -> (Addr, Addr) {
+        let collection_id = app.store_code(collection_contract());
+        let minter_id = app.store_code(minter_contract());
+
+        let minter_addr = app
             .instantiate_contract(
-                liquidity_id,
+                minter_id,
                 Addr::unchecked(ADMIN),
-                &msg,
+                &CreateMinterMsg::<Option<String>> {
+                    init_msg: None,
+                    collection_params: CollectionParams {
+                        code_id: collection_id,
+                        name: String::from("New Collection"),
+                        symbol: String::from("NEW"),
+                        info: CollectionInfo {
+                            creator: creator.to_string(),
+                            description: String::from("mock"),
+                            image: String::from("ipfs://mock"),
+                            external_link: None,
+                            explicit_content: None,
+                            start_trading_time: None,
+                            royalty_info: None,
+                        },
+                    },
+                },
                 &[],
-                "test",
-                None,
+                "minter",
+                Some(creator.to_string()),
+            )
+            .unwrap();
+
+        let minter_config: Minter_MockConfigResponse = app
+            .wrap()
+            .query_wasm_smart(minter_addr.clone(), &Minter_MockQueryMsg::Config {})
+            .unwrap();
+
+        (minter_addr, Addr::unchecked(minter_config.collection_address))
+    }
+
+    fn query_tokens(app: &App, collection: &str, owner: &str) -> Vec<String> {
+        let res: TokensResponse = app
+            .wrap()
+            .query_wasm_smart(
+                collection,
+                &Collection_MockQueryMsg::Tokens { owner: owner.to_string(), start_after: None, limit: None },
             )
             .unwrap();
+        res.tokens
+    }
+
+    fn bid(app: &mut App, auction_contract: &BraneAuctionContract, bidder: &str, amount: u128) -> anyhow::Result<AppResponse> {
+        let config = auction_contract.config(&app.wrap()).unwrap();
+        let cosmos_msg = auction_contract.bid(&config, amount).unwrap();
+        app.execute(Addr::unchecked(bidder), cosmos_msg)
+    }
+
+    //The contract's bid_denom balance is exactly the escrowed highest bid, the bidders' refunds, the treasury & the held submission fees
+    fn assert_escrow(app: &App, auction_contract: &BraneAuctionContract) {
+        let escrowed = auction_contract.live_auction(&app.wrap()).unwrap().auction
+            .map(|live_auction| live_auction.highest_bid.amount)
+            .unwrap_or_default();
+        let refunds: u128 = [BIDDER_1, BIDDER_2].iter()
+            .flat_map(|bidder| auction_contract.refunds(&app.wrap(), bidder.to_string()).unwrap().refunds)
+            .map(|refund| refund.amount.u128())
+            .sum();
+        let treasury = auction_contract.treasury(&app.wrap()).unwrap();
+        let treasury: u128 = treasury.balances.iter()
+            .chain(treasury.held_submission_fees.iter())
+            .filter(|balance| balance.denom == BID_DENOM)
+            .map(|balance| balance.amount.u128())
+            .sum();
+        assert_eq!(
+            app.wrap().query_balance(auction_contract.addr(), BID_DENOM).unwrap().amount,
+            Uint128::new(escrowed + refunds + treasury)
+        );
+    }
 
-        let liquidity_contract = LiquidityContract(liquidity_contract_addr);
+    fn submit(app: &mut App, auction_contract: &BraneAuctionContract, submitter: &str, token_uri: &str) -> anyhow::Result<AppResponse> {
+        let config = auction_contract.config(&app.wrap()).unwrap();
+        let cosmos_msg = auction_contract.submit_nft(
+            &config,
+            String::from(submitter),
+            String::from(submitter),
+            String::from(token_uri),
+            None,
+            false,
+        ).unwrap();
+        app.execute(Addr::unchecked(submitter), cosmos_msg)
+    }
+
+    fn mint_first_token(app: &mut App, auction_contract: &BraneAuctionContract) {
+        bid(app, auction_contract, BIDDER_2, 100_000_000).unwrap();
+        skip_auction_period(app);
+        conclude(app, auction_contract).unwrap();
+    }
+
+    //Sell the first token & curate a submission from each submitter, the first goes live & the rest are queued
+    fn queue_auctions(app: &mut App, auction_contract: &BraneAuctionContract, submitters: &[&str]) {
+        mint_first_token(app, auction_contract);
+        for (index, submitter) in submitters.iter().enumerate() {
+            submit(app, auction_contract, submitter, &format!("ipfs://submission_{}", index)).unwrap();
+            let submission_id = auction_contract.config(&app.wrap()).unwrap().current_submission_id - 1;
+            let cosmos_msg = auction_contract.vote_to_curate(vec![submission_id], true).unwrap();
+            app.execute(Addr::unchecked(BIDDER_2), cosmos_msg).unwrap();
+        }
+    }
+
+    fn claim_refunds(app: &mut App, auction_contract: &BraneAuctionContract, bidder: &str) -> anyhow::Result<AppResponse> {
+        let cosmos_msg = auction_contract.claim_refunds().unwrap();
+        app.execute(Addr::unchecked(bidder), cosmos_msg)
+    }
 
-        (app, liquidity_contract)
+    fn update_config(app: &mut App, auction_contract: &BraneAuctionContract, update: UpdateConfig) -> anyhow::Result<AppResponse> {
+        let cosmos_msg = auction_contract.update_config(update).unwrap();
+        app.execute(Addr::unchecked(ADMIN), cosmos_msg)
     }
 
-    mod liquidity {
+    fn conclude(app: &mut App, auction_contract: &BraneAuctionContract) -> anyhow::Result<AppResponse> {
+        let cosmos_msg = auction_contract.conclude_auction().unwrap();
+        app.execute(Addr::unchecked(USER), cosmos_msg)
+    }
+
+    fn skip_auction_period(app: &mut App) {
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(SECONDS_PER_DAY + 1);
+            block.height += 1;
+        });
+    }
 
-        use membrane::liquidity_check::{Config, LiquidityResponse};
+    mod auction {
 
         use super::*;
-        use membrane::types::LiquidityInfo;
 
         #[test]
-        fn add_edit_remove() {
-            let (mut app, liquidity_contract) = proper_instantiate();
+        fn instantiate() {
+            let (app, auction_contract) = proper_instantiate();
 
-            //Unauthorized AddAsset: Error
-            let msg = ExecuteMsg::AddAsset {
-                asset: LiquidityInfo {
-                    asset: AssetInfo::NativeToken {
-                        denom: String::from("credit_fulldenom"),
-                    },
-                    pool_infos: vec![ PoolType::Balancer { pool_id: 1 } ],
-                },
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(config.owner, Addr::unchecked(ADMIN));
+            assert_eq!(config.bid_denom, String::from(BID_DENOM));
+            assert_ne!(config.minter_addr, String::from(""));
+            assert_ne!(config.collection_addr, String::from(""));
+            assert_ne!(config.minter_addr, config.collection_addr);
+            assert_eq!(config.current_token_id, 1u64);
 
-            //Successful AddAsset
-            let msg = ExecuteMsg::AddAsset {
-                asset: LiquidityInfo {
-                    asset: AssetInfo::NativeToken {
-                        denom: String::from("credit_fulldenom"),
-                    },
-                    pool_infos: vec![ PoolType::Balancer { pool_id: 1 } ],
-                },
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            //The first submission is live
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.auction.submission_id, 0u64);
+            assert_eq!(live_auction.token_uri, String::from("ipfs://first_submission"));
+            assert_eq!(live_auction.seconds_remaining, SECONDS_PER_DAY);
+            assert_eq!(live_auction.minimum_next_bid, 1u128);
+        }
 
-            //Duplicate AddAsset: Error
-            let msg = ExecuteMsg::AddAsset {
-                asset: LiquidityInfo {
-                    asset: AssetInfo::NativeToken {
-                        denom: String::from("credit_fulldenom"),
-                    },
-                    pool_infos: vec![ PoolType::Balancer { pool_id: 1 } ],
-                },
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+        #[test]
+        fn instantiate_collection_params() {
+            let mut app = mock_app();
 
-            //Query Asset(s)
-            let assets: Vec<LiquidityInfo> = app
-                .wrap()
-                .query_wasm_smart(
-                    liquidity_contract.addr(),
-                    &QueryMsg::Assets {
-                        asset_info: Some(AssetInfo::NativeToken {
-                            denom: String::from("credit_fulldenom"),
-                        }),
-                        limit: None,
-                        start_after: None,
-                    },
-                )
-                .unwrap();
-            assert_eq!(assets[0].pool_infos, vec![ PoolType::Balancer { pool_id: 1 } ]);
+            //Invalid image: Error
+            instantiate_auction(&mut app, first_submission(), MINT_COST, 0, CollectionParamsMsg {
+                image: String::from("not a uri"),
+                ..Default::default()
+            }).unwrap_err();
+            //Invalid first submission: Error
+            instantiate_auction(&mut app, FirstSubmission {
+                token_uri: String::from("not a uri"),
+                ..first_submission()
+            }, MINT_COST, 0, collection_params()).unwrap_err();
+            //Royalty share above the cap: Error
+            instantiate_auction(&mut app, first_submission(), MINT_COST, 0, CollectionParamsMsg {
+                royalty_share: Some(Decimal::percent(11)),
+                ..collection_params()
+            }).unwrap_err();
+            //Start trading time in the past: Error
+            let now = app.block_info().time;
+            instantiate_auction(&mut app, first_submission(), MINT_COST, 0, CollectionParamsMsg {
+                start_trading_time: Some(now.minus_seconds(1)),
+                ..collection_params()
+            }).unwrap_err();
 
-            //Successful EditAsset
-            let msg = ExecuteMsg::EditAsset {
-                asset: LiquidityInfo {
-                    asset: AssetInfo::NativeToken {
-                        denom: String::from("credit_fulldenom"),
-                    },
-                    pool_infos: vec![ PoolType::Balancer { pool_id: 2 }, PoolType::Balancer { pool_id: 3 }, PoolType::Balancer { pool_id: 4 } ],
-                },
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked("cdp"), cosmos_msg).unwrap();
-
-            //Successful AddAsset: 2nd Asset
-            let msg = ExecuteMsg::AddAsset {
-                asset: LiquidityInfo {
-                    asset: AssetInfo::NativeToken {
-                        denom: String::from("credit_two"),
-                    },
-                    pool_infos: vec![ PoolType::Balancer { pool_id: 99 } ],
-                },
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            //Another community's collection
+            instantiate_auction(&mut app, first_submission(), MINT_COST, 0, CollectionParamsMsg {
+                image: String::from("ipfs://other_image"),
+                name: Some(String::from("Other Collection")),
+                symbol: Some(String::from("OTHER")),
+                royalty_share: Some(Decimal::percent(5)),
+                royalty_payment_address: Some(String::from(ARTIST)),
+                start_trading_time: Some(now.plus_seconds(SECONDS_PER_DAY)),
+                explicit_content: Some(true),
+                ..Default::default()
+            }).unwrap();
+        }
 
-            //Query Asset(s)
-            let assets: Vec<LiquidityInfo> = app
-                .wrap()
-                .query_wasm_smart(
-                    liquidity_contract.addr(),
-                    &QueryMsg::Assets {
-                        asset_info: None,
-                        limit: None,
-                        start_after: None,
-                    },
-                )
-                .unwrap();
-            assert_eq!(assets.len(), 2u64 as usize);
-            assert_eq!(assets[0].pool_infos, vec![ PoolType::Balancer { pool_id: 1 }, PoolType::Balancer { pool_id: 2 }, PoolType::Balancer { pool_id: 3 }, PoolType::Balancer { pool_id: 4 } ],);
+        #[test]
+        fn bid() {
+            let (mut app, auction_contract) = proper_instantiate();
 
-            //Successful RemoveAsset
-            let msg = ExecuteMsg::RemoveAsset {
-                asset: AssetInfo::NativeToken {
-                    denom: String::from("credit_fulldenom"),
-                },
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            //Invalid asset: Error
+            let cosmos_msg = auction_contract.call(ExecuteMsg::Bid {}, vec![coin(1_000, "not_bid_denom")]).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap_err();
+            //No funds: Error
+            let cosmos_msg = auction_contract.call(ExecuteMsg::Bid {}, vec![]).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap_err();
+            assert_escrow(&app, &auction_contract);
 
-            //Query Asset was removed
-            let assets: Vec<LiquidityInfo> = app
-                .wrap()
-                .query_wasm_smart(
-                    liquidity_contract.addr(),
-                    &QueryMsg::Assets {
-                        asset_info: None,
-                        limit: None,
-                        start_after: None,
-                    },
-                )
-                .unwrap();
-            assert_eq!(assets.len(), 1u64 as usize);
+            //First bid is recorded & escrowed
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.highest_bid.bidder, Addr::unchecked(BIDDER_1));
+            assert_eq!(live_auction.highest_bid.amount, 100_000_000u128);
+            assert_eq!(live_auction.auction.bids.len(), 1);
+            assert_escrow(&app, &auction_contract);
+
+            //Bid equal to the highest bid: Error
+            super::bid(&mut app, &auction_contract, BIDDER_2, 100_000_000).unwrap_err();
+            assert_escrow(&app, &auction_contract);
+
+            //Outbid
+            super::bid(&mut app, &auction_contract, BIDDER_2, 200_000_000).unwrap();
+            assert_escrow(&app, &auction_contract);
+
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.highest_bid.bidder, Addr::unchecked(BIDDER_2));
+            assert_eq!(live_auction.highest_bid.amount, 200_000_000u128);
+            assert_eq!(live_auction.auction.bids.len(), 2);
+
+            //Bid after the auction ended: Error
+            skip_auction_period(&mut app);
+            super::bid(&mut app, &auction_contract, BIDDER_2, 500_000_000).unwrap_err();
+            assert_escrow(&app, &auction_contract);
+
+            //The escrow is paid out on conclusion
+            conclude(&mut app, &auction_contract).unwrap();
+            assert_escrow(&app, &auction_contract);
         }
 
         #[test]
-        fn get_liquidity() {
-            let (mut app, liquidity_contract) = proper_instantiate();
+        fn outbid_refunds() {
+            let (mut app, auction_contract) = proper_instantiate();
 
-            //Successful AddAsset
-            let msg = ExecuteMsg::AddAsset {
-                asset: LiquidityInfo {
-                    asset: AssetInfo::NativeToken {
-                        denom: String::from("credit_fulldenom"),
-                    },
-                    pool_infos: vec![ PoolType::Balancer { pool_id: 1 }, PoolType::StableSwap { pool_id: 2 }],
-                },
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_2, 200_000_000).unwrap();
 
-            //Error: Invalid Asset
-            app.wrap()
-                .query_wasm_smart::<Uint128>(
-                    liquidity_contract.addr(),
-                    &QueryMsg::Liquidity {
-                        asset: AssetInfo::NativeToken {
-                            denom: String::from("invalid"),
-                        },
-                    },
-                )
-                .unwrap_err();
-
-            //Query Liquidity
-            let liquidity: LiquidityResponse = app
-                .wrap()
-                .query_wasm_smart(
-                    liquidity_contract.addr(),
-                    &QueryMsg::Liquidity {
-                        asset: AssetInfo::NativeToken {
-                            denom: String::from("credit_fulldenom"),
-                        },
-                    },
-                )
-                .unwrap();
-            //49_999 + 49_999 = 99_998 from two different pools
-            assert_eq!(liquidity.liquidity, Uint128::new(99_998u128));
+            //The outbid bidder can claim their refund
+            let res = auction_contract.refunds(&app.wrap(), String::from(BIDDER_1)).unwrap();
+            assert_eq!(res.refunds, vec![coin(100_000_000, BID_DENOM)]);
+            claim_refunds(&mut app, &auction_contract, BIDDER_1).unwrap();
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_1, BID_DENOM).unwrap().amount,
+                Uint128::new(1_000_000_000)
+            );
+            //Nothing left to claim: Error
+            claim_refunds(&mut app, &auction_contract, BIDDER_1).unwrap_err();
+            //Only the highest bid is escrowed
+            assert_eq!(
+                app.wrap().query_balance(auction_contract.addr(), BID_DENOM).unwrap().amount,
+                Uint128::new(200_000_000)
+            );
+            assert_escrow(&app, &auction_contract);
         }
 
         #[test]
-        fn update_config() {
-            let (mut app, liquidity_contract) = proper_instantiate();
+        fn bid_top_up() {
+            let (mut app, auction_contract) = proper_instantiate();
 
-            //Successful UpdateConfig
-            let msg = ExecuteMsg::UpdateConfig { 
-                owner: Some(String::from("new_owner")), 
-                osmosis_proxy: Some(String::from("new_op_contract")),  
-                positions_contract: Some(String::from("new_pos_contract")), 
-                stableswap_multiplier: Some(Decimal::one())
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_2, 200_000_000).unwrap();
 
-            
-            //Query Config
-            let config: Config = app
-                .wrap()
-                .query_wasm_smart(
-                    liquidity_contract.addr(),
-                    &QueryMsg::Config {},
-                )
-                .unwrap();
+            //The highest bidder tops up by sending only the difference
+            super::bid(&mut app, &auction_contract, BIDDER_2, 100_000_000).unwrap();
             assert_eq!(
-                config, 
-                Config {
-                    owner: Addr::unchecked(ADMIN), 
-                    osmosis_proxy:  Addr::unchecked("new_op_contract"),  
-                    positions_contract:  Addr::unchecked("new_pos_contract"), 
-                    stableswap_multiplier: Decimal::one(),
-            });
+                app.wrap().query_balance(BIDDER_2, BID_DENOM).unwrap().amount,
+                Uint128::new(700_000_000)
+            );
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.highest_bid.bidder, Addr::unchecked(BIDDER_2));
+            assert_eq!(live_auction.highest_bid.amount, 300_000_000u128);
+            assert_escrow(&app, &auction_contract);
 
-            //Ownership transfer
-            let msg = ExecuteMsg::UpdateConfig { 
-                owner: None,
-                osmosis_proxy: None,
-                positions_contract: None,
-                stableswap_multiplier: None,
-            };
-            let cosmos_msg = liquidity_contract.call(msg, vec![]).unwrap();
-            app.execute(Addr::unchecked("new_owner"), cosmos_msg).unwrap();
+            //Outbidding the topped up bid refunds all of it
+            super::bid(&mut app, &auction_contract, BIDDER_1, 400_000_000).unwrap();
+            assert_escrow(&app, &auction_contract);
+            claim_refunds(&mut app, &auction_contract, BIDDER_2).unwrap();
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_2, BID_DENOM).unwrap().amount,
+                Uint128::new(1_000_000_000)
+            );
+            assert_escrow(&app, &auction_contract);
+        }
 
-            
-            //Query Config
-            let config: Config = app
-                .wrap()
-                .query_wasm_smart(
-                    liquidity_contract.addr(),
-                    &QueryMsg::Config {},
-                )
-                .unwrap();
+        #[test]
+        fn bid_with_refund() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_2, 200_000_000).unwrap();
+
+            //The outbid bidder only sends the difference to their refund
+            let res = super::bid(&mut app, &auction_contract, BIDDER_1, 150_000_000).unwrap();
+            assert!(res.events.iter().any(|e| e.attributes.iter().any(|attr| attr.key == "refund_used" && attr.value == "100000000")));
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.highest_bid.bidder, Addr::unchecked(BIDDER_1));
+            assert_eq!(live_auction.highest_bid.amount, 250_000_000u128);
+            assert_eq!(auction_contract.refunds(&app.wrap(), String::from(BIDDER_1)).unwrap().refunds, vec![]);
             assert_eq!(
-                config, 
-                Config {
-                    owner: Addr::unchecked("new_owner"), 
-                    osmosis_proxy:  Addr::unchecked("new_op_contract"),  
-                    positions_contract:  Addr::unchecked("new_pos_contract"), 
-                    stableswap_multiplier: Decimal::one(),
-            });
+                app.wrap().query_balance(BIDDER_1, BID_DENOM).unwrap().amount,
+                Uint128::new(750_000_000)
+            );
+            assert_escrow(&app, &auction_contract);
+
+            //Top up
+            super::bid(&mut app, &auction_contract, BIDDER_1, 50_000_000).unwrap();
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap();
+            assert_escrow(&app, &auction_contract);
+
+            //Top-ups replace the bid they raised in the memecoin split: 100M, 200M & 300M bids
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_1, MEME_DENOM).unwrap().amount,
+                Uint128::new(66_666_666)
+            );
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_2, MEME_DENOM).unwrap().amount,
+                Uint128::new(33_333_333)
+            );
+        }
+
+        #[test]
+        fn conclude_auction() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_2, 200_000_000).unwrap();
+
+            //Conclude while live: Error
+            conclude(&mut app, &auction_contract).unwrap_err();
+
+            //Conclude
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap();
+
+            //Proceeds sent to the artist
+            assert_eq!(
+                app.wrap().query_balance(ARTIST, BID_DENOM).unwrap().amount,
+                Uint128::new(200_000_000)
+            );
+            //Only the outbid refund is left
+            assert_eq!(
+                app.wrap().query_balance(auction_contract.addr(), BID_DENOM).unwrap().amount,
+                Uint128::new(100_000_000)
+            );
+            assert_escrow(&app, &auction_contract);
+
+            //The NFT was minted to the winner
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(query_tokens(&app, &config.collection_addr, BIDDER_2), vec![String::from("1")]);
+
+            //Memecoins split pro-rata between bidders
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_1, MEME_DENOM).unwrap().amount,
+                Uint128::new(MEMECOIN_DISTRIBUTION / 3)
+            );
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_2, MEME_DENOM).unwrap().amount,
+                Uint128::new(MEMECOIN_DISTRIBUTION * 2 / 3)
+            );
+
+            //No auction left
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap();
+            assert_eq!(live_auction.auction, None);
+
+            //Conclude twice: Error
+            conclude(&mut app, &auction_contract).unwrap_err();
+        }
+
+        #[test]
+        fn conclude_without_bids() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap();
+
+            //Nothing minted or distributed
+            assert_eq!(
+                app.wrap().query_balance(ARTIST, BID_DENOM).unwrap().amount,
+                Uint128::zero()
+            );
+            assert_eq!(
+                app.wrap().query_balance(auction_contract.addr(), MEME_DENOM).unwrap().amount,
+                Uint128::new(1_000_000_000)
+            );
+        }
+
+        #[test]
+        fn min_bid_increment() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //No increment: Error
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                min_bid_increment: Some(BidIncrement::Absolute(0)),
+                ..Default::default()
+            }).unwrap_err();
+
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                min_bid_increment: Some(BidIncrement::Percent(Decimal::percent(10))),
+                ..Default::default()
+            }).unwrap();
+
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+
+            //Under a 10% increment: Error
+            super::bid(&mut app, &auction_contract, BIDDER_2, 105_000_000).unwrap_err();
+
+            super::bid(&mut app, &auction_contract, BIDDER_2, 110_000_000).unwrap();
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.minimum_next_bid, 121_000_000u128);
+
+            //Absolute increment
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                min_bid_increment: Some(BidIncrement::Absolute(5_000_000)),
+                ..Default::default()
+            }).unwrap();
+            //The outbid bidder's 100_000_000 refund is added to their bid
+            super::bid(&mut app, &auction_contract, BIDDER_1, 14_999_999).unwrap_err();
+            super::bid(&mut app, &auction_contract, BIDDER_1, 15_000_000).unwrap();
+
+            //Top-ups must meet the increment too
+            super::bid(&mut app, &auction_contract, BIDDER_1, 4_999_999).unwrap_err();
+            super::bid(&mut app, &auction_contract, BIDDER_1, 5_000_000).unwrap();
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.highest_bid.amount, 120_000_000u128);
+        }
+
+        #[test]
+        fn anti_sniping() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                max_extension: Some(900),
+                ..Default::default()
+            }).unwrap();
+
+            let original_end_time = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap().auction.auction_end_time;
+            let set_time = |app: &mut App, seconds: u64| {
+                app.update_block(|block| {
+                    block.time = cosmwasm_std::Timestamp::from_seconds(seconds);
+                    block.height += 1;
+                });
+            };
+
+            //Outside the window: no extension
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            let auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap().auction;
+            assert_eq!(auction.auction_end_time, original_end_time);
+
+            //Inside the window: the end time moves to 600 seconds after the bid
+            set_time(&mut app, original_end_time - 300);
+            let res = super::bid(&mut app, &auction_contract, BIDDER_2, 200_000_000).unwrap();
+            assert!(res.events.iter().any(|e| e.attributes.iter().any(|attr| attr.key == "auction_end_time" && attr.value == (original_end_time + 300).to_string())));
+
+            set_time(&mut app, original_end_time + 200);
+            super::bid(&mut app, &auction_contract, BIDDER_1, 300_000_000).unwrap();
+            let auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap().auction;
+            assert_eq!(auction.auction_end_time, original_end_time + 800);
+
+            //Capped at the max extension
+            set_time(&mut app, original_end_time + 700);
+            super::bid(&mut app, &auction_contract, BIDDER_2, 400_000_000).unwrap();
+            let auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap().auction;
+            assert_eq!(auction.auction_end_time, original_end_time + 900);
+            assert_eq!(auction.total_extension, 900u64);
+
+            //Can't conclude before the extended end time: Error
+            set_time(&mut app, original_end_time + 850);
+            conclude(&mut app, &auction_contract).unwrap_err();
+            set_time(&mut app, original_end_time + 901);
+            conclude(&mut app, &auction_contract).unwrap();
+        }
+
+        #[test]
+        fn auction_history_indexes() {
+            use crate::query::{query_auction_by_token_id, query_auctions_by_artist, query_auctions_by_submitter, query_auctions_by_winner};
+            use crate::state::{auction_history, AuctionRecord};
+            use cosmwasm_std::testing::mock_dependencies;
+
+            let mut deps = mock_dependencies();
+            let sold = AuctionRecord {
+                auction_id: 0,
+                submission_id: 0,
+                token_id: Some(String::from("1")),
+                winner: Some(Addr::unchecked(BIDDER_1)),
+                winning_amount: 100_000_000,
+                submitter: Addr::unchecked(USER),
+                proceed_recipient: Addr::unchecked(ARTIST),
+                token_uri: String::from("https://sold.com"),
+                curators: vec![],
+                bid_count: 1,
+                start_time: 0,
+                end_time: SECONDS_PER_DAY,
+                memecoins_distributed: 0,
+                cancel_reason: None,
+            };
+            auction_history().save(deps.as_mut().storage, 0, &sold).unwrap();
+            auction_history().save(deps.as_mut().storage, 1, &AuctionRecord {
+                auction_id: 1,
+                token_id: None,
+                winner: None,
+                winning_amount: 0,
+                ..sold
+            }).unwrap();
+
+            //Artists are the proceed recipients
+            let res = query_auctions_by_artist(deps.as_ref(), String::from(ARTIST), None, None).unwrap();
+            assert_eq!(res.auctions.len(), 2);
+            let res = query_auctions_by_artist(deps.as_ref(), String::from(USER), None, None).unwrap();
+            assert_eq!(res.auctions.len(), 0);
+            let res = query_auctions_by_submitter(deps.as_ref(), String::from(USER), None, None).unwrap();
+            assert_eq!(res.auctions.len(), 2);
+            //The unsold auction is indexed under an empty token ID & winner, which queries skip
+            let unsold = auction_history().idx.token_id
+                .prefix(String::new())
+                .keys(deps.as_ref().storage, None, None, cosmwasm_std::Order::Ascending)
+                .count();
+            assert_eq!(unsold, 1);
+            query_auction_by_token_id(deps.as_ref(), String::new()).unwrap_err();
+            query_auctions_by_winner(deps.as_ref(), String::new(), None, None).unwrap_err();
+            let res = query_auctions_by_winner(deps.as_ref(), String::from(BIDDER_1), None, None).unwrap();
+            assert_eq!(res.auctions.len(), 1);
+            let record = query_auction_by_token_id(deps.as_ref(), String::from("1")).unwrap();
+            assert_eq!(record.auction_id, 0u64);
+        }
+
+        #[test]
+        fn cancel_auction_promotes_pending_auction() {
+            let (mut app, auction_contract) = proper_instantiate();
+            queue_auctions(&mut app, &auction_contract, &[USER, BIDDER_1]);
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            app.update_block(|block| block.time = block.time.plus_seconds(3_600));
+            let now = app.block_info().time.seconds();
+
+            let cosmos_msg = auction_contract.cancel_auction(String::from("plagiarized"), false).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            //The queued auction goes live w/ fresh timings
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.auction.submission_id, 2u64);
+            assert_eq!(live_auction.auction.auction_start_time, now);
+            assert_eq!(live_auction.auction.auction_end_time, now + SECONDS_PER_DAY);
+            assert_eq!(live_auction.auction.total_extension, 0u64);
+            assert_eq!(live_auction.highest_bid.amount, 0u128);
+            assert_eq!(live_auction.auction.bids.len(), 0);
+            let res = auction_contract.pending_auctions(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.pending_auctions.len(), 0);
+
+            //The cancelled submission's fee goes to the treasury, the promoted one's stays held
+            let treasury = auction_contract.treasury(&app.wrap()).unwrap();
+            assert_eq!(treasury.balances, vec![coin(SUBMISSION_COST, BID_DENOM)]);
+            assert_eq!(treasury.held_submission_fees, vec![coin(SUBMISSION_COST, BID_DENOM)]);
+            assert_escrow(&app, &auction_contract);
+        }
+
+        #[test]
+        fn cancel_auction() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_2, 200_000_000).unwrap();
+
+            //Unauthorized: Error
+            let cosmos_msg = auction_contract.cancel_auction(String::from("plagiarized"), true).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+            //No reason: Error
+            let cosmos_msg = auction_contract.cancel_auction(String::new(), true).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Returning to curation is held to the submission limit: Error
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                submission_limit: Some(1),
+                ..Default::default()
+            }).unwrap();
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            let cosmos_msg = auction_contract.submit_nft(
+                &config,
+                String::from(USER),
+                String::from(USER),
+                String::from("ipfs://submission"),
+                None,
+                false,
+            ).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
+            let cosmos_msg = auction_contract.cancel_auction(String::from("plagiarized"), true).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                submission_limit: Some(2),
+                ..Default::default()
+            }).unwrap();
+
+            //Successful cancellation
+            let cosmos_msg = auction_contract.cancel_auction(String::from("plagiarized"), true).unwrap();
+            let res = app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            assert!(res.events.iter().any(|e| e.attributes.iter().any(|attr| attr.key == "refunded_bidder" && attr.value == BIDDER_2)));
+
+            //All bids refunded, only the submission fee is left
+            assert_escrow(&app, &auction_contract);
+            claim_refunds(&mut app, &auction_contract, BIDDER_1).unwrap();
+            claim_refunds(&mut app, &auction_contract, BIDDER_2).unwrap();
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_1, BID_DENOM).unwrap().amount,
+                Uint128::new(1_000_000_000)
+            );
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_2, BID_DENOM).unwrap().amount,
+                Uint128::new(1_000_000_000)
+            );
+            assert_eq!(
+                app.wrap().query_balance(auction_contract.addr(), BID_DENOM).unwrap().amount,
+                Uint128::new(SUBMISSION_COST)
+            );
+
+            //No pending auction to promote
+            assert_eq!(auction_contract.live_auction(&app.wrap()).unwrap().auction, None);
+
+            //The reason is archived
+            let history = auction_contract.auction_history(&app.wrap(), None, None).unwrap();
+            assert_eq!(history.auctions[0].cancel_reason, Some(String::from("plagiarized")));
+            assert_eq!(history.auctions[0].winner, None);
+
+            //The submission is back in curation under a new ID
+            let res = auction_contract.submissions(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.submissions.len(), 2);
+            assert_eq!(res.submissions[1].submission_id, 2u64);
+            assert_eq!(res.submissions[1].submission.submission.token_uri, String::from("ipfs://first_submission"));
+            assert_eq!(res.submissions[1].submission.curation_votes, Vec::<Addr>::new());
+            assert_eq!(auction_contract.config(&app.wrap()).unwrap().submission_total, 2u64);
+
+            //The cancelled auction's bids stay with the old ID
+            let res = auction_contract.bids_by_bidder(&app.wrap(), String::from(BIDDER_1), None, None).unwrap();
+            assert_eq!(res.auctions.len(), 1);
+            assert_eq!(res.auctions[0].submission_id, 0u64);
+
+            //Nothing left to cancel: Error
+            let cosmos_msg = auction_contract.cancel_auction(String::from("plagiarized"), false).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Without bids there's no refunded bidder
+            let (mut app, auction_contract) = proper_instantiate();
+            let cosmos_msg = auction_contract.cancel_auction(String::from("plagiarized"), false).unwrap();
+            let res = app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            assert!(res.events.iter().any(|e| e.attributes.iter().any(|attr| attr.key == "refunded_bidder" && attr.value == "none")));
+        }
+    }
+
+    mod curation {
+
+        use super::*;
+
+        //Conclude the first auction so BIDDER_2 holds token 1
+        #[test]
+        fn submissions_pagination() {
+            let (mut app, auction_contract) = proper_instantiate();
+            for index in 0..34 {
+                submit(&mut app, &auction_contract, BIDDER_1, &format!("ipfs://submission_{}", index)).unwrap();
+            }
+            let submission_ids = |limit: Option<u32>, start_after: Option<u64>| -> Vec<u64> {
+                auction_contract.submissions(&app.wrap(), limit, start_after).unwrap()
+                    .submissions.iter()
+                    .map(|submission| submission.submission_id)
+                    .collect()
+            };
+
+            //Default limit
+            assert_eq!(submission_ids(None, None), (1..=10).collect::<Vec<u64>>());
+            //Next pages start after the last ID
+            assert_eq!(submission_ids(None, Some(10)), (11..=20).collect::<Vec<u64>>());
+            assert_eq!(submission_ids(Some(3), Some(5)), vec![6, 7, 8]);
+            assert_eq!(submission_ids(None, Some(30)), vec![31, 32, 33, 34]);
+            assert_eq!(submission_ids(None, Some(34)), Vec::<u64>::new());
+            //Capped at the max limit
+            assert_eq!(submission_ids(Some(100), None), (1..=32).collect::<Vec<u64>>());
+        }
+
+        #[test]
+        fn submit_nft() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //Invalid token URI: Error
+            submit(&mut app, &auction_contract, USER, "not a uri").unwrap_err();
+
+            //Non-holder without the submission cost: Error
+            let cosmos_msg = auction_contract.call(ExecuteMsg::SubmitNFT {
+                submitter: String::from(USER),
+                proceed_recipient: String::from(USER),
+                token_uri: String::from("ipfs://submission"),
+                reserve_price: None,
+            }, vec![]).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+            //Overpaying the submission cost: Error
+            let cosmos_msg = auction_contract.call(ExecuteMsg::SubmitNFT {
+                submitter: String::from(USER),
+                proceed_recipient: String::from(USER),
+                token_uri: String::from("ipfs://submission"),
+                reserve_price: None,
+            }, vec![coin(SUBMISSION_COST + 1, BID_DENOM)]).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Successful submission
+            submit(&mut app, &auction_contract, USER, "ipfs://submission").unwrap();
+
+            let res = auction_contract.submissions(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.submissions.len(), 1);
+            assert_eq!(res.submissions[0].submission_id, 1u64);
+            assert_eq!(res.submissions[0].submission.submission.submitter, Addr::unchecked(USER));
+
+            //Indexed by submitter
+            let res = auction_contract.submissions_by_submitter(&app.wrap(), String::from(USER), None, None).unwrap();
+            assert_eq!(res.submissions.len(), 1);
+
+            //Holder sending a submission cost: Error
+            mint_first_token(&mut app, &auction_contract);
+            submit(&mut app, &auction_contract, BIDDER_2, "ipfs://holder_submission").unwrap_err();
+            //Holders submit for free
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            let cosmos_msg = auction_contract.submit_nft(
+                &config,
+                String::from(BIDDER_2),
+                String::from(BIDDER_2),
+                String::from("ipfs://holder_submission"),
+                None,
+                true,
+            ).unwrap();
+            app.execute(Addr::unchecked(BIDDER_2), cosmos_msg).unwrap();
+        }
+
+        #[test]
+        fn reserve_price() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            let cosmos_msg = auction_contract.submit_nft(
+                &config,
+                String::from(USER),
+                String::from(USER),
+                String::from("ipfs://submission"),
+                Some(500_000_000),
+                false,
+            ).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
+
+            mint_first_token(&mut app, &auction_contract);
+            let cosmos_msg = auction_contract.vote_to_curate(vec![1], true).unwrap();
+            app.execute(Addr::unchecked(BIDDER_2), cosmos_msg).unwrap();
+
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.reserve_price, Some(500_000_000u128));
+
+            //Bids under the reserve are accepted
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap();
+
+            //Settled unsold & refunded
+            assert_eq!(
+                auction_contract.refunds(&app.wrap(), String::from(BIDDER_1)).unwrap().refunds,
+                vec![coin(100_000_000, BID_DENOM)]
+            );
+            claim_refunds(&mut app, &auction_contract, BIDDER_1).unwrap();
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_1, BID_DENOM).unwrap().amount,
+                Uint128::new(1_000_000_000)
+            );
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(query_tokens(&app, &config.collection_addr, BIDDER_1), Vec::<String>::new());
+            let history = auction_contract.auction_history(&app.wrap(), None, None).unwrap();
+            assert_eq!(history.auctions[1].winner, None);
+            assert_eq!(history.auctions[1].token_id, None);
+        }
+
+        #[test]
+        fn take_down_submission() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            submit(&mut app, &auction_contract, USER, "ipfs://submission").unwrap();
+
+            //Non-moderator: Error
+            let cosmos_msg = auction_contract.take_down_submission(1, String::from("stolen"), true).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap_err();
+
+            let cosmos_msg = auction_contract.update_moderators(vec![String::from(BIDDER_1)], vec![]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            //Moderator takes it down & refunds the fee
+            let cosmos_msg = auction_contract.take_down_submission(1, String::from("stolen"), true).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap();
+
+            assert_eq!(
+                app.wrap().query_balance(USER, BID_DENOM).unwrap().amount,
+                Uint128::new(100_000_000)
+            );
+            let res = auction_contract.submissions(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.submissions.len(), 0);
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(config.submission_total, 0u64);
+
+            let takedown = auction_contract.takedown(&app.wrap(), 1).unwrap();
+            assert_eq!(takedown.reason, String::from("stolen"));
+            assert_eq!(takedown.moderator, Addr::unchecked(BIDDER_1));
+            assert_eq!(takedown.refunded_fee, Some(coin(SUBMISSION_COST, BID_DENOM)));
+            let res = auction_contract.takedowns(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.takedowns.len(), 1);
+
+            //Already taken down: Error
+            let cosmos_msg = auction_contract.take_down_submission(1, String::from("stolen"), true).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Removed moderator: Error
+            submit(&mut app, &auction_contract, USER, "ipfs://explicit").unwrap();
+            let cosmos_msg = auction_contract.update_moderators(vec![], vec![String::from(BIDDER_1)]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            let cosmos_msg = auction_contract.take_down_submission(2, String::from("explicit"), false).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap_err();
+
+            //Owner takes it down without a refund
+            let cosmos_msg = auction_contract.take_down_submission(2, String::from("explicit"), false).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            assert_eq!(auction_contract.takedown(&app.wrap(), 2).unwrap().refunded_fee, None);
+        }
+
+        #[test]
+        fn queries_by_address() {
+            let (mut app, auction_contract) = proper_instantiate();
+            queue_auctions(&mut app, &auction_contract, &[USER, BIDDER_1, USER]);
+
+            //Queued auctions by submitter & recipient
+            let res = auction_contract.pending_auctions_by_submitter(&app.wrap(), String::from(USER), None, None).unwrap();
+            assert_eq!(res.pending_auctions.len(), 1);
+            assert_eq!(res.pending_auctions[0].auction.submission_id, 3u64);
+            assert_eq!(res.pending_auctions[0].position, 1u32);
+            let res = auction_contract.pending_auctions_by_recipient(&app.wrap(), String::from(BIDDER_1), None, None).unwrap();
+            assert_eq!(res.pending_auctions.len(), 1);
+            assert_eq!(res.pending_auctions[0].auction.submission_id, 2u64);
+            assert_eq!(res.pending_auctions[0].position, 0u32);
+            let res = auction_contract.pending_auctions_by_submitter(&app.wrap(), String::from(USER), None, Some(1)).unwrap();
+            assert_eq!(res.pending_auctions.len(), 0);
+
+            //Concluded auctions by submitter
+            let res = auction_contract.auctions_by_submitter(&app.wrap(), String::from(ARTIST), None, None).unwrap();
+            assert_eq!(res.auctions.len(), 1);
+            let res = auction_contract.auctions_by_submitter(&app.wrap(), String::from(USER), None, None).unwrap();
+            assert_eq!(res.auctions.len(), 0);
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap();
+            let res = auction_contract.auctions_by_submitter(&app.wrap(), String::from(USER), None, None).unwrap();
+            assert_eq!(res.auctions.len(), 1);
+            assert_eq!(res.auctions[0].submission_id, 1u64);
+        }
+
+        #[test]
+        fn pending_auction_start_times() {
+            let (mut app, auction_contract) = proper_instantiate();
+            queue_auctions(&mut app, &auction_contract, &[USER, BIDDER_1, USER, BIDDER_1]);
+            let live_end_time = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap().auction.auction_end_time;
+
+            //Each queued auction starts an auction period after the one before it, from the live auction's end
+            let res = auction_contract.pending_auctions(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.pending_auctions.len(), 3);
+            for (position, pending_auction) in res.pending_auctions.iter().enumerate() {
+                assert_eq!(pending_auction.position, position as u32);
+                assert_eq!(pending_auction.auction.submission_id, position as u64 + 2);
+                assert_eq!(pending_auction.estimated_start_time, live_end_time + (position as u64 * SECONDS_PER_DAY));
+
+                let res = auction_contract.pending_auction_position(&app.wrap(), position as u64 + 2).unwrap();
+                assert_eq!(res, pending_auction.clone());
+            }
+            //Not queued: Error
+            auction_contract.pending_auction_position(&app.wrap(), 1).unwrap_err();
+
+            //Once the live auction is overdue, the queue starts now
+            app.update_block(|block| block.time = block.time.plus_seconds(2 * SECONDS_PER_DAY));
+            let now = app.block_info().time.seconds();
+            let res = auction_contract.pending_auction_position(&app.wrap(), 4).unwrap();
+            assert_eq!(res.estimated_start_time, now + (2 * SECONDS_PER_DAY));
+        }
+
+        #[test]
+        fn pending_auction_start_times_without_live_auction() {
+            use crate::contracts::instantiate;
+            use crate::query::{query_pending_auction_position, query_pending_auctions};
+            use crate::state::{AUCTION, PENDING_AUCTION};
+            use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+            let mut deps = mock_dependencies();
+            let env = mock_env();
+            instantiate(deps.as_mut(), env.clone(), mock_info(ADMIN, &[coin(250_000_000, NATIVE_DENOM)]), InstantiateMsg {
+                sg721_code_id: 1,
+                base_factory_address: String::from("factory"),
+                bid_denom: String::from(BID_DENOM),
+                memecoin_denom: None,
+                first_submission: first_submission(),
+                mint_cost: MINT_COST,
+                collection_params: collection_params(),
+            }).unwrap();
+            let auction = AUCTION.load(deps.as_ref().storage).unwrap();
+            AUCTION.remove(deps.as_mut().storage);
+            PENDING_AUCTION.save(deps.as_mut().storage, &vec![
+                auction.clone(),
+                crate::state::Auction { submission_id: 1, ..auction },
+            ]).unwrap();
+
+            //The queue starts now
+            let now = env.block.time.seconds();
+            let res = query_pending_auctions(deps.as_ref(), env.clone(), None, None).unwrap();
+            assert_eq!(res.pending_auctions[0].estimated_start_time, now);
+            assert_eq!(res.pending_auctions[1].estimated_start_time, now + SECONDS_PER_DAY);
+            let res = query_pending_auction_position(deps.as_ref(), env, 1).unwrap();
+            assert_eq!(res.position, 1u32);
+            assert_eq!(res.estimated_start_time, now + SECONDS_PER_DAY);
+        }
+
+        #[test]
+        fn take_down_pending_auction() {
+            let (mut app, auction_contract) = proper_instantiate();
+            queue_auctions(&mut app, &auction_contract, &[USER, BIDDER_1]);
+            let balance = app.wrap().query_balance(BIDDER_1, BID_DENOM).unwrap().amount;
+
+            //The live auction can't be taken down: Error
+            let cosmos_msg = auction_contract.take_down_submission(1, String::from("stolen"), true).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Queued submission is removed & its fee refunded
+            let cosmos_msg = auction_contract.take_down_submission(2, String::from("stolen"), true).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            let res = auction_contract.pending_auctions(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.pending_auctions.len(), 0);
+            auction_contract.pending_auction_position(&app.wrap(), 2).unwrap_err();
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_1, BID_DENOM).unwrap().amount,
+                balance + Uint128::new(SUBMISSION_COST)
+            );
+            assert_eq!(auction_contract.takedown(&app.wrap(), 2).unwrap().refunded_fee, Some(coin(SUBMISSION_COST, BID_DENOM)));
+            //Only the live auction's fee is still held
+            assert_eq!(auction_contract.treasury(&app.wrap()).unwrap().held_submission_fees, vec![coin(SUBMISSION_COST, BID_DENOM)]);
+            assert_escrow(&app, &auction_contract);
+        }
+
+        #[test]
+        fn vote_with_staked_power() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //Stake before the submission
+            let staking_id = app.store_code(staking_contract());
+            let staking_addr = app
+                .instantiate_contract(staking_id, Addr::unchecked(ADMIN), &Empty {}, &[], "staking", None)
+                .unwrap();
+            for (curator, power) in [(USER, 50u128), (BIDDER_1, 50u128)] {
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    staking_addr.clone(),
+                    &Staking_MockExecuteMsg::SetPower { address: String::from(curator), power },
+                    &[],
+                ).unwrap();
+            }
+
+            //Not a staking contract: Error
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                voting_power_source: Some(auction_contract.addr().to_string()),
+                ..Default::default()
+            }).unwrap_err();
+
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                voting_power_source: Some(staking_addr.to_string()),
+                curation_threshold: Some(Decimal::percent(60)),
+                ..Default::default()
+            }).unwrap();
+
+            app.update_block(|block| block.height += 1);
+            submit(&mut app, &auction_contract, USER, "ipfs://submission").unwrap();
+
+            //Staked in the submission's block, after the snapshot: Error
+            app.execute_contract(
+                Addr::unchecked(ADMIN),
+                staking_addr,
+                &Staking_MockExecuteMsg::SetPower { address: String::from(BIDDER_2), power: 100 },
+                &[],
+            ).unwrap();
+            app.update_block(|block| block.height += 1);
+            let cosmos_msg = auction_contract.vote_to_curate(vec![1], true).unwrap();
+            app.execute(Addr::unchecked(BIDDER_2), cosmos_msg).unwrap_err();
+
+            //50 of 100 power is under the threshold, no NFT held
+            let cosmos_msg = auction_contract.vote_to_curate(vec![1], true).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
+            let res = auction_contract.submissions(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.submissions[0].submission.curation_power, 50u128);
+
+            //100 of 100 power queues the submission behind the live auction
+            let cosmos_msg = auction_contract.vote_to_curate(vec![1], true).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap();
+            let res = auction_contract.pending_auctions(&app.wrap(), None, None).unwrap();
+            assert_eq!(res.pending_auctions.len(), 1);
+            assert_eq!(res.pending_auctions[0].auction.submission_id, 1u64);
+        }
+
+        #[test]
+        fn vote_to_curate() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            submit(&mut app, &auction_contract, USER, "ipfs://submission").unwrap();
+
+            //Non-holder vote: Error
+            let cosmos_msg = auction_contract.vote_to_curate(vec![1], true).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap_err();
+
+            mint_first_token(&mut app, &auction_contract);
+
+            //Holder vote curates the submission into the live auction
+            let cosmos_msg = auction_contract.vote_to_curate(vec![1], true).unwrap();
+            app.execute(Addr::unchecked(BIDDER_2), cosmos_msg).unwrap();
+
+            let live_auction = auction_contract.live_auction(&app.wrap()).unwrap().auction.unwrap();
+            assert_eq!(live_auction.auction.submission_id, 1u64);
+            assert_eq!(live_auction.proceed_recipient, Addr::unchecked(USER));
+            assert_eq!(live_auction.auction.submission_info.curation_votes, vec![Addr::unchecked(BIDDER_2)]);
+
+            let votes = auction_contract.votes_by_curator(&app.wrap(), String::from(BIDDER_2), None, None).unwrap();
+            assert_eq!(votes.submission_ids, vec![1u64]);
+
+            //Bid & conclude the curated auction
+            super::bid(&mut app, &auction_contract, BIDDER_1, 50_000_000).unwrap();
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap();
+
+            //Proceeds sent to the submitter's recipient
+            assert_eq!(
+                app.wrap().query_balance(USER, BID_DENOM).unwrap().amount,
+                Uint128::new(100_000_000 - SUBMISSION_COST + 50_000_000)
+            );
+            //Token 2 minted to the winner
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(query_tokens(&app, &config.collection_addr, BIDDER_1), vec![String::from("2")]);
+            //The curator received the curator share on top of their bidder share from the first auction
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_2, MEME_DENOM).unwrap().amount,
+                Uint128::new(MEMECOIN_DISTRIBUTION + MEMECOIN_DISTRIBUTION)
+            );
+            //The sole bidder received the full bidder share
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_1, MEME_DENOM).unwrap().amount,
+                Uint128::new(MEMECOIN_DISTRIBUTION)
+            );
+
+            //Both auctions are archived
+            let history = auction_contract.auction_history(&app.wrap(), None, None).unwrap();
+            assert_eq!(history.auctions.len(), 2);
+            let record = auction_contract.auction_by_token_id(&app.wrap(), String::from("2")).unwrap();
+            assert_eq!(record.winner, Some(Addr::unchecked(BIDDER_1)));
+            assert_eq!(record.curators, vec![Addr::unchecked(BIDDER_2)]);
+            let won = auction_contract.auctions_by_winner(&app.wrap(), String::from(BIDDER_2), None, None).unwrap();
+            assert_eq!(won.auctions[0].token_id, Some(String::from("1")));
+        }
+    }
+
+    mod config {
+
+        use super::*;
+
+        #[test]
+        fn instantiate_mint_cost_bounds() {
+            let mut app = mock_app();
+
+            //Zero or above the cap: Error
+            instantiate_auction(&mut app, first_submission(), 0u128, 0, collection_params()).unwrap_err();
+            instantiate_auction(&mut app, first_submission(), 100_000_000_001u128, 0, collection_params()).unwrap_err();
+
+            //Mainnet mint cost
+            let auction_contract = BraneAuctionContract(instantiate_auction(&mut app, first_submission(), MAINNET_MINT_COST, 0, collection_params()).unwrap());
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(config.mint_cost, MAINNET_MINT_COST);
+        }
+
+        #[test]
+        fn update_config() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //Unauthorized: Error
+            let cosmos_msg = auction_contract.update_config(UpdateConfig {
+                auction_period: Some(2),
+                ..Default::default()
+            }).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Out of bounds: Error
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                auction_period: Some(8),
+                ..Default::default()
+            }).unwrap_err();
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                curation_threshold: Some(Decimal::percent(101)),
+                ..Default::default()
+            }).unwrap_err();
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                submission_vote_period: Some(0),
+                ..Default::default()
+            }).unwrap_err();
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                memecoin_distribution_amount: Some(0u128),
+                ..Default::default()
+            }).unwrap_err();
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                mint_cost: Some(0u128),
+                ..Default::default()
+            }).unwrap_err();
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                mint_cost: Some(100_000_000_001u128),
+                ..Default::default()
+            }).unwrap_err();
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                submission_cost: Some(100_000_000_001u128),
+                ..Default::default()
+            }).unwrap_err();
+
+            //Successful UpdateConfig
+            let res = super::update_config(&mut app, &auction_contract, UpdateConfig {
+                bid_denom: Some(String::from("new_bid_denom")),
+                memecoin_distribution_amount: Some(1_000_000_000u128),
+                mint_cost: Some(MAINNET_MINT_COST),
+                submission_cost: Some(2_000_000u128),
+                submission_limit: Some(3u64),
+                submission_vote_period: Some(4u64),
+                curation_threshold: Some(Decimal::percent(50)),
+                auction_period: Some(5u64),
+                ..Default::default()
+            }).unwrap();
+            //Old & new values are logged
+            assert!(res.events.iter().any(|e| e.attributes.iter().any(|attr| attr.key == "auction_period" && attr.value == "1 -> 5")));
+
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(config.bid_denom, String::from("new_bid_denom"));
+            assert_eq!(config.memecoin_distribution_amount, 1_000_000_000u128);
+            assert_eq!(config.mint_cost, MAINNET_MINT_COST);
+            assert_eq!(config.submission_cost, 2_000_000u128);
+            assert_eq!(config.submission_limit, 3u64);
+            assert_eq!(config.submission_vote_period, 4u64);
+            assert_eq!(config.curation_threshold, Decimal::percent(50));
+            assert_eq!(config.auction_period, 5u64);
+        }
+
+        #[test]
+        fn bid_denom_locked_by_escrow() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+
+            //The live auction holds bids in the old denom: Error
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                bid_denom: Some(String::from("new_bid_denom")),
+                ..Default::default()
+            }).unwrap_err();
+        }
+
+        #[test]
+        fn ownership_transfer() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //Unauthorized proposal: Error
+            let cosmos_msg = auction_contract.propose_new_owner(String::from(USER), None).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Propose w/ a 1 day expiry
+            let cosmos_msg = auction_contract.propose_new_owner(String::from("new_owner"), Some(SECONDS_PER_DAY)).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            let transfer = auction_contract.ownership_transfer(&app.wrap()).unwrap().unwrap();
+            assert_eq!(transfer.new_owner, Addr::unchecked("new_owner"));
+
+            //Only the proposed owner can accept: Error
+            let cosmos_msg = auction_contract.accept_ownership().unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Expired: Error
+            skip_auction_period(&mut app);
+            let cosmos_msg = auction_contract.accept_ownership().unwrap();
+            app.execute(Addr::unchecked("new_owner"), cosmos_msg).unwrap_err();
+
+            //Cancel
+            let cosmos_msg = auction_contract.cancel_ownership_transfer().unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            assert_eq!(auction_contract.ownership_transfer(&app.wrap()).unwrap(), None);
+
+            //Propose & accept
+            let cosmos_msg = auction_contract.propose_new_owner(String::from("new_owner"), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            let cosmos_msg = auction_contract.accept_ownership().unwrap();
+            app.execute(Addr::unchecked("new_owner"), cosmos_msg).unwrap();
+
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(config.owner, Addr::unchecked("new_owner"));
+            assert_eq!(auction_contract.ownership_transfer(&app.wrap()).unwrap(), None);
+
+            //The previous owner lost authority
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                auction_period: Some(2),
+                ..Default::default()
+            }).unwrap_err();
+        }
+
+        #[test]
+        fn pause_state() {
+            let (mut app, auction_contract) = proper_instantiate();
+            let config = auction_contract.config(&app.wrap()).unwrap();
+
+            //Only the owner sets the guardian
+            let cosmos_msg = auction_contract.update_guardian(Some(String::from(USER))).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+            let cosmos_msg = auction_contract.update_guardian(Some(String::from(USER))).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            //Non-guardian pause: Error
+            let cosmos_msg = auction_contract.update_pause_state(UpdatePauseState {
+                bidding: Some(true),
+                ..Default::default()
+            }).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap_err();
+
+            //Guardian pauses submissions & settlement
+            let cosmos_msg = auction_contract.update_pause_state(UpdatePauseState {
+                submissions: Some(true),
+                settlement: Some(true),
+                ..Default::default()
+            }).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
+
+            let pause_state = auction_contract.pause_state(&app.wrap()).unwrap();
+            assert!(pause_state.submissions && pause_state.settlement);
+            assert!(!pause_state.curation && !pause_state.bidding);
+
+            //Submissions are paused: Error
+            let cosmos_msg = auction_contract.submit_nft(
+                &config,
+                String::from(USER),
+                String::from(USER),
+                String::from("ipfs://submission"),
+                None,
+                false,
+            ).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Bidding still refunds outbid bidders
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_2, 200_000_000).unwrap();
+
+            //Refunds are claimable while everything is paused
+            let cosmos_msg = auction_contract.update_pause_state(UpdatePauseState {
+                curation: Some(true),
+                bidding: Some(true),
+                ..Default::default()
+            }).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
+            claim_refunds(&mut app, &auction_contract, BIDDER_1).unwrap();
+            assert_eq!(
+                app.wrap().query_balance(BIDDER_1, BID_DENOM).unwrap().amount,
+                Uint128::new(1_000_000_000)
+            );
+
+            //Settlement is paused: Error
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap_err();
+
+            //Guardian unpause: Error
+            let cosmos_msg = auction_contract.update_pause_state(UpdatePauseState {
+                settlement: Some(false),
+                ..Default::default()
+            }).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Owner unpauses
+            let cosmos_msg = auction_contract.update_pause_state(UpdatePauseState {
+                settlement: Some(false),
+                ..Default::default()
+            }).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            conclude(&mut app, &auction_contract).unwrap();
+        }
+    }
+
+    mod treasury {
+
+        use super::*;
+
+        #[test]
+        fn withdraw_treasury() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //Submission fees are held until their submission is finalized
+            submit(&mut app, &auction_contract, USER, "ipfs://submission").unwrap();
+            submit(&mut app, &auction_contract, USER, "ipfs://refunded_submission").unwrap();
+            let treasury = auction_contract.treasury(&app.wrap()).unwrap();
+            assert_eq!(treasury.balances, vec![]);
+            assert_eq!(treasury.held_submission_fees, vec![coin(SUBMISSION_COST * 2, BID_DENOM)]);
+            //Held fees can't be withdrawn: Error
+            let cosmos_msg = auction_contract.withdraw_treasury(String::from(BID_DENOM), Some(1), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //A takedown without a refund finalizes the fee into the treasury
+            let cosmos_msg = auction_contract.take_down_submission(1, String::from("spam"), false).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            let treasury = auction_contract.treasury(&app.wrap()).unwrap();
+            assert_eq!(treasury.balances, vec![coin(SUBMISSION_COST, BID_DENOM)]);
+            assert_eq!(treasury.held_submission_fees, vec![coin(SUBMISSION_COST, BID_DENOM)]);
+
+            //Escrow the live auction's bid
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+
+            //Unauthorized: Error
+            let cosmos_msg = auction_contract.withdraw_treasury(String::from(BID_DENOM), None, None).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+            //More than the treasury, though the escrow could cover it: Error
+            let cosmos_msg = auction_contract.withdraw_treasury(String::from(BID_DENOM), Some(SUBMISSION_COST + 1), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+            //Mint fee reserve isn't in the treasury: Error
+            let cosmos_msg = auction_contract.withdraw_treasury(String::from(NATIVE_DENOM), Some(1), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Successful withdrawal
+            let cosmos_msg = auction_contract.withdraw_treasury(String::from(BID_DENOM), None, Some(String::from(ARTIST))).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            assert_eq!(
+                app.wrap().query_balance(ARTIST, BID_DENOM).unwrap().amount,
+                Uint128::new(SUBMISSION_COST)
+            );
+            assert_eq!(auction_contract.treasury(&app.wrap()).unwrap().balances, vec![coin(0, BID_DENOM)]);
+
+            //The escrow & the held fee are untouched
+            assert_eq!(
+                app.wrap().query_balance(auction_contract.addr(), BID_DENOM).unwrap().amount,
+                Uint128::new(100_000_000 + SUBMISSION_COST)
+            );
+            //The held fee is still refundable after the withdrawal
+            let cosmos_msg = auction_contract.take_down_submission(2, String::from("stolen"), true).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            assert_eq!(auction_contract.treasury(&app.wrap()).unwrap().held_submission_fees, vec![coin(0, BID_DENOM)]);
+            assert_escrow(&app, &auction_contract);
+        }
+
+        #[test]
+        fn mint_reserve() {
+            let mut app = mock_app();
+            //Seeded w/ the ustars sent above the minter creation fee
+            let auction_contract = BraneAuctionContract(instantiate_auction(&mut app, first_submission(), MINT_COST, MINT_COST, collection_params()).unwrap());
+            assert_eq!(auction_contract.mint_reserve(&app.wrap()).unwrap(), coin(MINT_COST, NATIVE_DENOM));
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            skip_auction_period(&mut app);
+
+            //Only the mint denom: Error
+            let cosmos_msg = auction_contract.fund_mint_reserve(vec![coin(MINT_COST, BID_DENOM)]).unwrap();
+            app.execute(Addr::unchecked(BIDDER_1), cosmos_msg).unwrap_err();
+
+            //Top up the reserve
+            let cosmos_msg = auction_contract.fund_mint_reserve(vec![coin(1, NATIVE_DENOM)]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            assert_eq!(auction_contract.mint_reserve(&app.wrap()).unwrap(), coin(MINT_COST + 1, NATIVE_DENOM));
+
+            //The reserve isn't in the treasury: Error
+            let cosmos_msg = auction_contract.withdraw_treasury(String::from(NATIVE_DENOM), Some(1), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //The sale's mint fee is debited from the reserve
+            super::conclude(&mut app, &auction_contract).unwrap();
+            assert_eq!(auction_contract.mint_reserve(&app.wrap()).unwrap(), coin(1, NATIVE_DENOM));
+            assert_eq!(
+                app.wrap().query_balance(auction_contract.addr(), NATIVE_DENOM).unwrap().amount,
+                Uint128::new(1)
+            );
+        }
+
+        #[test]
+        fn mint_reserve_short() {
+            let mut app = mock_app();
+            let auction_contract = BraneAuctionContract(instantiate_auction(&mut app, first_submission(), MINT_COST, 0, collection_params()).unwrap());
+            assert_eq!(auction_contract.mint_reserve(&app.wrap()).unwrap(), coin(0, NATIVE_DENOM));
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            skip_auction_period(&mut app);
+
+            //Stray ustars don't pay mint fees
+            app.send_tokens(Addr::unchecked(ADMIN), auction_contract.addr(), &[coin(MINT_COST, NATIVE_DENOM)]).unwrap();
+
+            //The reserve can't cover the mint cost, the auction settles unsold & the highest bid is refunded
+            let res = super::conclude(&mut app, &auction_contract).unwrap();
+            assert!(res.events.iter().any(|e| e.attributes.iter().any(|attr| attr.key == "sold" && attr.value == "false")));
+            assert!(res.events.iter().any(|e| e.attributes.iter().any(|attr| attr.key == "mint_reserve_short" && attr.value == "true")));
+            assert_eq!(
+                auction_contract.refunds(&app.wrap(), String::from(BIDDER_1)).unwrap().refunds,
+                vec![coin(100_000_000, BID_DENOM)]
+            );
+            assert_escrow(&app, &auction_contract);
+        }
+    }
+
+    mod royalties {
+
+        use super::*;
+        use crate::msgs::{RoyaltySink, RoyaltySplit};
+
+        const SINK: &str = "sink";
+
+        fn distribute(app: &mut App, auction_contract: &BraneAuctionContract, denom: Option<&str>) -> anyhow::Result<AppResponse> {
+            let cosmos_msg = auction_contract.distribute_royalties(denom.map(String::from)).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg)
+        }
+
+        #[test]
+        fn distribute_royalties() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //Over 100%: Error
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                royalty_split: Some(RoyaltySplit {
+                    artist_share: Decimal::percent(95),
+                    sinks: vec![RoyaltySink { address: Addr::unchecked(SINK), share: Decimal::percent(10) }],
+                }),
+                ..Default::default()
+            }).unwrap_err();
+
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                royalty_split: Some(RoyaltySplit {
+                    artist_share: Decimal::percent(50),
+                    sinks: vec![RoyaltySink { address: Addr::unchecked(SINK), share: Decimal::percent(10) }],
+                }),
+                ..Default::default()
+            }).unwrap();
+
+            //Escrowed bids & refunds aren't royalties: Error
+            super::bid(&mut app, &auction_contract, BIDDER_1, 50_000_000).unwrap();
+            super::bid(&mut app, &auction_contract, BIDDER_2, 100_000_000).unwrap();
+            distribute(&mut app, &auction_contract, None).unwrap_err();
+            claim_refunds(&mut app, &auction_contract, BIDDER_1).unwrap();
+
+            //Secondary sale royalties
+            app.send_tokens(Addr::unchecked(BIDDER_1), auction_contract.addr(), &[coin(10_000_000, BID_DENOM)]).unwrap();
+
+            //Unauthorized: Error
+            let cosmos_msg = auction_contract.distribute_royalties(None).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+            //Memecoins fund distributions: Error
+            distribute(&mut app, &auction_contract, Some(MEME_DENOM)).unwrap_err();
+
+            //No sold tokens yet, so the artist share goes to the treasury
+            distribute(&mut app, &auction_contract, None).unwrap();
+            assert_eq!(app.wrap().query_balance(SINK, BID_DENOM).unwrap().amount, Uint128::new(1_000_000));
+            assert_eq!(auction_contract.treasury(&app.wrap()).unwrap().balances, vec![coin(9_000_000, BID_DENOM)]);
+            //The escrow is untouched & the treasury stays in the contract
+            assert_eq!(
+                app.wrap().query_balance(auction_contract.addr(), BID_DENOM).unwrap().amount,
+                Uint128::new(100_000_000 + 9_000_000)
+            );
+
+            //Sell token 1 for the artist
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap();
+
+            app.send_tokens(Addr::unchecked(BIDDER_1), auction_contract.addr(), &[coin(10_000_000, BID_DENOM)]).unwrap();
+            distribute(&mut app, &auction_contract, None).unwrap();
+            assert_eq!(app.wrap().query_balance(SINK, BID_DENOM).unwrap().amount, Uint128::new(2_000_000));
+            assert_eq!(auction_contract.treasury(&app.wrap()).unwrap().balances, vec![coin(13_000_000, BID_DENOM)]);
+
+            let res = auction_contract.artist_royalties(&app.wrap(), String::from(ARTIST)).unwrap();
+            assert_eq!(res.token_count, 1u64);
+            assert_eq!(res.claimable, 5_000_000u128);
+
+            //Unclaimed artist royalties aren't redistributed: Error
+            distribute(&mut app, &auction_contract, None).unwrap_err();
+
+            //No sold tokens: Error
+            let cosmos_msg = auction_contract.claim_royalties().unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            let cosmos_msg = auction_contract.claim_royalties().unwrap();
+            app.execute(Addr::unchecked(ARTIST), cosmos_msg).unwrap();
+            assert_eq!(
+                app.wrap().query_balance(ARTIST, BID_DENOM).unwrap().amount,
+                Uint128::new(100_000_000 + 5_000_000)
+            );
+            //Only the treasury is left
+            assert_eq!(
+                app.wrap().query_balance(auction_contract.addr(), BID_DENOM).unwrap().amount,
+                Uint128::new(13_000_000)
+            );
+            assert_eq!(auction_contract.artist_royalties(&app.wrap(), String::from(ARTIST)).unwrap().claimable, 0u128);
+
+            //Royalties still arrive in the old bid_denom after it changes
+            super::update_config(&mut app, &auction_contract, UpdateConfig {
+                bid_denom: Some(String::from("new_bid_denom")),
+                ..Default::default()
+            }).unwrap();
+            app.send_tokens(Addr::unchecked(BIDDER_1), auction_contract.addr(), &[coin(10_000_000, BID_DENOM)]).unwrap();
+            //Nothing in the new bid_denom: Error
+            distribute(&mut app, &auction_contract, None).unwrap_err();
+
+            //The artist share of old denom royalties goes to the treasury
+            distribute(&mut app, &auction_contract, Some(BID_DENOM)).unwrap();
+            assert_eq!(app.wrap().query_balance(SINK, BID_DENOM).unwrap().amount, Uint128::new(3_000_000));
+            assert_eq!(auction_contract.treasury(&app.wrap()).unwrap().balances, vec![coin(22_000_000, BID_DENOM)]);
+            assert_eq!(auction_contract.artist_royalties(&app.wrap(), String::from(ARTIST)).unwrap().claimable, 0u128);
+        }
+    }
+
+    mod minter {
+
+        use super::*;
+
+        #[test]
+        fn collection_reply() {
+            use crate::contracts::{instantiate, reply};
+            use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+            use cosmwasm_std::{ContractResult, Event, Reply, SubMsgResponse, SubMsgResult, SystemResult, WasmQuery};
+
+            let mut deps = mock_dependencies();
+            instantiate(deps.as_mut(), mock_env(), mock_info(ADMIN, &[coin(250_000_000, NATIVE_DENOM)]), InstantiateMsg {
+                sg721_code_id: 1,
+                base_factory_address: String::from("factory"),
+                bid_denom: String::from(BID_DENOM),
+                memecoin_denom: None,
+                first_submission: first_submission(),
+                mint_cost: MINT_COST,
+                collection_params: collection_params(),
+            }).unwrap();
+            let collection_reply = |events: Vec<Event>| Reply {
+                id: 1,
+                result: SubMsgResult::Ok(SubMsgResponse { events, data: None }),
+            };
+
+            //No collection address: Error
+            reply(deps.as_mut(), mock_env(), collection_reply(vec![Event::new("wasm").add_attribute("_contract_address", "minter")])).unwrap_err();
+
+            //The minter's reply event carries its address
+            reply(deps.as_mut(), mock_env(), collection_reply(vec![
+                Event::new("instantiate").add_attribute("_contract_address", "minter").add_attribute("code_id", "2"),
+                Event::new("instantiate").add_attribute("_contract_address", "collection").add_attribute("code_id", "1"),
+                Event::new("wasm")
+                    .add_attribute("_contract_address", "minter")
+                    .add_attribute("action", "instantiate_sg721_reply")
+                    .add_attribute("sg721_address", "collection"),
+            ])).unwrap();
+            let config = crate::state::CONFIG.load(deps.as_ref().storage).unwrap();
+            assert_eq!(config.minter_addr, String::from("minter"));
+            assert_eq!(config.collection_addr, String::from("collection"));
+
+            //Without it, the collection is queried for its minter
+            deps.querier.update_wasm(|query| match query {
+                WasmQuery::Smart { contract_addr, .. } if contract_addr == "other_collection" => SystemResult::Ok(ContractResult::Ok(
+                    Binary::from(br#"{"minter":"other_minter"}"#.as_slice())
+                )),
+                _ => SystemResult::Ok(ContractResult::Err(String::from("unknown contract"))),
+            });
+            reply(deps.as_mut(), mock_env(), collection_reply(vec![
+                Event::new("wasm").add_attribute("sg721_address", "other_collection"),
+            ])).unwrap();
+            let config = crate::state::CONFIG.load(deps.as_ref().storage).unwrap();
+            assert_eq!(config.minter_addr, String::from("other_minter"));
+            assert_eq!(config.collection_addr, String::from("other_collection"));
+        }
+
+        #[test]
+        fn migrate_minter() {
+            let (mut app, auction_contract) = proper_instantiate();
+            let old_config = auction_contract.config(&app.wrap()).unwrap();
+
+            let (new_minter, new_collection) = create_minter(&mut app, &auction_contract.addr());
+            let (foreign_minter, _) = create_minter(&mut app, &Addr::unchecked(USER));
+
+            //Unauthorized: Error
+            let cosmos_msg = auction_contract.migrate_minter(new_minter.to_string(), None).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Not the new collection's creator: Error
+            let cosmos_msg = auction_contract.migrate_minter(foreign_minter.to_string(), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Live auction has bids: Error
+            super::bid(&mut app, &auction_contract, BIDDER_1, 100_000_000).unwrap();
+            let cosmos_msg = auction_contract.migrate_minter(new_minter.to_string(), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Settle the live auction, minting token 1 from the old minter
+            skip_auction_period(&mut app);
+            conclude(&mut app, &auction_contract).unwrap();
+
+            //Successful migration
+            let cosmos_msg = auction_contract.migrate_minter(new_minter.to_string(), Some(String::from("successor"))).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(config.minter_addr, new_minter.to_string());
+            assert_eq!(config.collection_addr, new_collection.to_string());
+            //Token IDs follow the new minter's token index
+            assert_eq!(config.current_token_id, 1u64);
+
+            //The old minter's admin was handed to the successor
+            let contract_info = app.wrap().query_wasm_contract_info(old_config.minter_addr).unwrap();
+            assert_eq!(contract_info.admin, Some(String::from("successor")));
+
+            //The next minter has already minted a token
+            let (next_minter, _) = create_minter(&mut app, &auction_contract.addr());
+            app.execute_contract(auction_contract.addr(), next_minter.clone(), &Minter_MockExecuteMsg::Mint { token_uri: String::from("https://minted.com") }, &[]).unwrap();
+
+            //This contract isn't the current minter's admin: Error
+            app.execute(auction_contract.addr(), cosmwasm_std::CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+                contract_addr: new_minter.to_string(),
+                admin: String::from(USER),
+            })).unwrap();
+            let cosmos_msg = auction_contract.migrate_minter(next_minter.to_string(), Some(String::from("successor"))).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Without a successor the admin is left alone
+            let cosmos_msg = auction_contract.migrate_minter(next_minter.to_string(), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            let config = auction_contract.config(&app.wrap()).unwrap();
+            assert_eq!(config.minter_addr, next_minter.to_string());
+            assert_eq!(config.current_token_id, 2u64);
+            let contract_info = app.wrap().query_wasm_contract_info(new_minter).unwrap();
+            assert_eq!(contract_info.admin, Some(String::from(USER)));
+
+            //Ended auction that isn't concluded: Error
+            let (mut app, auction_contract) = proper_instantiate();
+            let (new_minter, _) = create_minter(&mut app, &auction_contract.addr());
+            skip_auction_period(&mut app);
+            let cosmos_msg = auction_contract.migrate_minter(new_minter.to_string(), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+            conclude(&mut app, &auction_contract).unwrap();
+            let cosmos_msg = auction_contract.migrate_minter(new_minter.to_string(), None).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+        }
+    }
+
+    mod migration {
+
+        use super::*;
+        use crate::contracts::migrate;
+        use crate::migrations::{AuctionV0_1, ConfigV0_1, SubmissionInfoV0_1, SubmissionItemV0_1, AUCTION_V0_1, CONFIG_V0_1, PENDING_AUCTION_V0_1, SUBMISSIONS_V0_1};
+        use crate::msgs::MigrateMsg;
+        use crate::state::{submissions, AUCTION, CONFIG, CURATOR_VOTES, PENDING_AUCTION, Bid};
+        use cosmwasm_std::testing::{mock_dependencies, mock_env};
+        use cosmwasm_std::{ContractResult, SystemResult, WasmQuery};
+
+        fn legacy_submission(token_uri: &str) -> SubmissionItemV0_1 {
+            SubmissionItemV0_1 {
+                submission: SubmissionInfoV0_1 {
+                    submitter: Addr::unchecked(ARTIST),
+                    proceed_recipient: Addr::unchecked(ARTIST),
+                    token_uri: String::from(token_uri),
+                },
+                curation_votes: vec![Addr::unchecked(USER)],
+                submission_end_time: 0,
+            }
+        }
+
+        #[test]
+        fn migrate_from_v0_1() {
+            let mut deps = mock_dependencies();
+            let env = mock_env();
+
+            //v0.1.0 state
+            cw2::set_contract_version(deps.as_mut().storage, "brane_auction", "0.1.0").unwrap();
+            CONFIG_V0_1.save(deps.as_mut().storage, &ConfigV0_1 {
+                owner: Addr::unchecked(ADMIN),
+                bid_denom: String::from(BID_DENOM),
+                memecoin_denom: Some(String::from(MEME_DENOM)),
+                memecoin_distribution_amount: MEMECOIN_DISTRIBUTION,
+                current_token_id: 0,
+                current_submission_id: 2,
+                minter_addr: String::from("minter"),
+                mint_cost: MINT_COST,
+                submission_cost: SUBMISSION_COST,
+                submission_limit: 100,
+                submission_total: 1,
+                submission_vote_period: 7,
+                curation_threshold: Decimal::percent(11),
+                auction_period: 1,
+            }).unwrap();
+            SUBMISSIONS_V0_1.save(deps.as_mut().storage, 1, &legacy_submission("https://submission.com")).unwrap();
+            AUCTION_V0_1.save(deps.as_mut().storage, &AuctionV0_1 {
+                submission_info: legacy_submission("https://live.com"),
+                bids: vec![],
+                highest_bid: Bid { bidder: Addr::unchecked(""), amount: 0 },
+                auction_end_time: env.block.time.seconds(),
+            }).unwrap();
+            PENDING_AUCTION_V0_1.save(deps.as_mut().storage, &vec![AuctionV0_1 {
+                submission_info: legacy_submission("https://pending.com"),
+                bids: vec![],
+                highest_bid: Bid { bidder: Addr::unchecked(""), amount: 0 },
+                auction_end_time: 0,
+            }]).unwrap();
+
+            //Migrating from another contract: Error
+            cw2::set_contract_version(deps.as_mut().storage, "other_contract", "0.1.0").unwrap();
+            migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap_err();
+
+            //The collection address can't be recovered from the minter: Error
+            cw2::set_contract_version(deps.as_mut().storage, "brane_auction", "0.1.0").unwrap();
+            migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap_err();
+
+            //The v0.1.0 minter knows the collection
+            deps.querier.update_wasm(|query| match query {
+                WasmQuery::Smart { contract_addr, .. } if contract_addr == "minter" => SystemResult::Ok(ContractResult::Ok(
+                    Binary::from(br#"{"collection_address":"collection","config":{}}"#.as_slice())
+                )),
+                _ => SystemResult::Ok(ContractResult::Err(String::from("unknown contract"))),
+            });
+
+            //Successful migration
+            cw2::set_contract_version(deps.as_mut().storage, "brane_auction", "0.1.0").unwrap();
+            let res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+            assert_eq!(res.attributes[1].value, "0.1.0");
+
+            let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+            assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+
+            //Config gained the new fields
+            let config = CONFIG.load(deps.as_ref().storage).unwrap();
+            assert_eq!(config.current_token_id, 1u64);
+            assert_eq!(config.current_auction_id, 0u64);
+            assert_eq!(config.current_submission_id, 4u64);
+            assert_eq!(config.collection_addr, String::from("collection"));
+
+            //Auctions got unused submission IDs
+            let auction = AUCTION.load(deps.as_ref().storage).unwrap();
+            assert_eq!(auction.submission_id, 2u64);
+            assert_eq!(auction.auction_start_time, env.block.time.seconds() - SECONDS_PER_DAY);
+            let pending_auctions = PENDING_AUCTION.load(deps.as_ref().storage).unwrap();
+            assert_eq!(pending_auctions[0].submission_id, 3u64);
+
+            //Submissions & votes are indexed
+            let submitted = submissions().idx.submitter
+                .prefix(Addr::unchecked(ARTIST))
+                .keys(deps.as_ref().storage, None, None, cosmwasm_std::Order::Ascending)
+                .collect::<StdResult<Vec<u64>>>()
+                .unwrap();
+            assert_eq!(submitted, vec![1u64]);
+            assert!(CURATOR_VOTES.has(deps.as_ref().storage, (&Addr::unchecked(USER), 1)));
+            let submission = submissions().load(deps.as_ref().storage, 1).unwrap();
+            assert_eq!(submission.curation_power, 1u128);
+            assert_eq!(submission.submission_height, env.block.height);
+
+            //Downgrade: Error
+            cw2::set_contract_version(deps.as_mut().storage, "brane_auction", "9.0.0").unwrap();
+            migrate(deps.as_mut(), env, MigrateMsg {}).unwrap_err();
         }
     }
 }
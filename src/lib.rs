@@ -6,6 +6,8 @@ pub mod contracts;
 pub mod error;
 pub mod helpers;
 pub mod integration_tests;
+pub mod migrations;
 pub mod state;
 pub mod msgs;
+pub mod query;
 pub mod reply;
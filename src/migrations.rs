@@ -0,0 +1,176 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, DepsMut, Order, StdResult};
+use cw_storage_plus::{Item, Map};
+use sg4::QueryMsg as BaseMinterQueryMsg;
+
+use crate::{contracts::{default_royalty_split, EXTENSION_LENGTH, EXTENSION_WINDOW, MAX_EXTENSION, MIN_BID_INCREMENT, SECONDS_PER_DAY}, error::ContractError, msgs::{Config, MinterConfigResponse}, state::{submissions, Auction, Bid, SubmissionInfo, SubmissionItem, AUCTION, CONFIG, CURATOR_VOTES, OWNERSHIP_TRANSFER, PAUSE_STATE, PENDING_AUCTION, PauseState, RoyaltyState, ROYALTY_STATE}};
+
+/// v0.1.0 Config layout
+#[cw_serde]
+pub struct ConfigV0_1 {
+    pub owner: Addr,
+    pub bid_denom: String,
+    pub memecoin_denom: Option<String>,
+    pub memecoin_distribution_amount: u128,
+    pub current_token_id: u64,
+    pub current_submission_id: u64,
+    pub minter_addr: String,
+    pub mint_cost: u128,
+    pub submission_cost: u128,
+    pub submission_limit: u64,
+    pub submission_total: u64,
+    pub submission_vote_period: u64,
+    pub curation_threshold: Decimal,
+    pub auction_period: u64,
+}
+
+/// v0.1.0 SubmissionItem layout
+#[cw_serde]
+pub struct SubmissionItemV0_1 {
+    pub submission: SubmissionInfoV0_1,
+    pub curation_votes: Vec<Addr>,
+    pub submission_end_time: u64,
+}
+
+#[cw_serde]
+pub struct SubmissionInfoV0_1 {
+    pub submitter: Addr,
+    pub proceed_recipient: Addr,
+    pub token_uri: String,
+}
+
+impl From<SubmissionItemV0_1> for SubmissionItem {
+    fn from(old: SubmissionItemV0_1) -> Self {
+        SubmissionItem {
+            submission: SubmissionInfo {
+                submitter: old.submission.submitter,
+                proceed_recipient: old.submission.proceed_recipient,
+                token_uri: old.submission.token_uri,
+                reserve_price: None,
+            },
+            curation_power: old.curation_votes.len() as u128,
+            curation_votes: old.curation_votes,
+            submission_height: 0, //set by the migration
+            submission_end_time: old.submission_end_time,
+            submission_fee: None, //not recorded in v0.1.0
+        }
+    }
+}
+
+/// v0.1.0 Auction layout
+#[cw_serde]
+pub struct AuctionV0_1 {
+    pub submission_info: SubmissionItemV0_1,
+    pub bids: Vec<Bid>,
+    pub highest_bid: Bid,
+    pub auction_end_time: u64,
+}
+
+//v0.1.0 storage, same namespaces as the current storage
+pub const CONFIG_V0_1: Item<ConfigV0_1> = Item::new("config");
+pub const SUBMISSIONS_V0_1: Map<u64, SubmissionItemV0_1> = Map::new("submissions");
+pub const PENDING_AUCTION_V0_1: Item<Vec<AuctionV0_1>> = Item::new("pending_auctions");
+pub const AUCTION_V0_1: Item<AuctionV0_1> = Item::new("current_auction");
+
+/// Migrate v0.1.0 state to v0.2.0
+/// - Config: adds the auction ID & the collection address, queried from the v0.1.0 minter. Token IDs start at 1 like the base-minter
+/// - Auction: adds the submission ID, start time & extension, queued auctions get new submission IDs
+/// - SubmissionItem: re-saved to build the submitter, recipient & curator indexes, voting power is snapshotted at the migration height
+/// - Pause state: nothing paused, no guardian
+/// - Royalties: default split, no sold tokens
+/// - Treasury: empty, untracked v0.1.0 fees are distributed as royalties
+/// - Mint reserve: empty, fund it or sales settle unsold
+pub fn migrate_from_v0_1(deps: DepsMut, height: u64) -> Result<(), ContractError> {
+    let old_config = CONFIG_V0_1.load(deps.storage)?;
+
+    //v0.1.0 didn't store the collection, its minter knows it
+    let minter_config: MinterConfigResponse = deps.querier.query_wasm_smart(old_config.minter_addr.clone(), &BaseMinterQueryMsg::Config {})
+        .map_err(|_| ContractError::CustomError { val: format!("Failed to query the collection address from the v0.1.0 minter: {}", old_config.minter_addr) })?;
+    let collection_addr = deps.api.addr_validate(&minter_config.collection_address)?;
+
+    let mut config = Config {
+        owner: old_config.owner,
+        guardian: None,
+        moderators: vec![],
+        bid_denom: old_config.bid_denom,
+        memecoin_denom: old_config.memecoin_denom,
+        memecoin_distribution_amount: old_config.memecoin_distribution_amount,
+        current_token_id: old_config.current_token_id.max(1),
+        current_auction_id: 0,
+        current_submission_id: old_config.current_submission_id,
+        minter_addr: old_config.minter_addr,
+        collection_addr: collection_addr.to_string(),
+        mint_cost: old_config.mint_cost,
+        submission_cost: old_config.submission_cost,
+        submission_limit: old_config.submission_limit,
+        submission_total: old_config.submission_total,
+        submission_vote_period: old_config.submission_vote_period,
+        curation_threshold: old_config.curation_threshold,
+        auction_period: old_config.auction_period,
+        voting_power_source: None,
+        royalty_split: default_royalty_split(),
+        min_bid_increment: MIN_BID_INCREMENT,
+        extension_window: EXTENSION_WINDOW,
+        extension_length: EXTENSION_LENGTH,
+        max_extension: MAX_EXTENSION,
+    };
+
+    //Auctions didn't store their submission ID, so they get unused ones
+    if let Some(old_auction) = AUCTION_V0_1.may_load(deps.storage)? {
+        let submission_id = config.current_submission_id;
+        config.current_submission_id += 1;
+
+        AUCTION.save(deps.storage, &Auction {
+            submission_id,
+            submission_info: old_auction.submission_info.into(),
+            bids: old_auction.bids,
+            highest_bid: old_auction.highest_bid,
+            auction_start_time: old_auction.auction_end_time.saturating_sub(config.auction_period * SECONDS_PER_DAY),
+            auction_end_time: old_auction.auction_end_time,
+            total_extension: 0,
+        })?;
+    }
+    let old_pending_auctions = PENDING_AUCTION_V0_1.may_load(deps.storage)?.unwrap_or_default();
+    let mut pending_auctions: Vec<Auction> = vec![];
+    for old_auction in old_pending_auctions {
+        let submission_id = config.current_submission_id;
+        config.current_submission_id += 1;
+
+        pending_auctions.push(Auction {
+            submission_id,
+            submission_info: old_auction.submission_info.into(),
+            bids: old_auction.bids,
+            highest_bid: old_auction.highest_bid,
+            auction_start_time: 0, //will set when active
+            auction_end_time: 0, //will set when active
+            total_extension: 0,
+        });
+    }
+    PENDING_AUCTION.save(deps.storage, &pending_auctions)?;
+
+    //Index existing submissions & their votes
+    let old_submissions = SUBMISSIONS_V0_1
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, SubmissionItemV0_1)>>>()?;
+    for (submission_id, submission) in old_submissions {
+        let mut submission: SubmissionItem = submission.into();
+        submission.submission_height = height;
+        for curator in submission.curation_votes.iter() {
+            CURATOR_VOTES.save(deps.storage, (curator, submission_id), &true)?;
+        }
+        submissions().replace(deps.storage, submission_id, Some(&submission), None)?;
+    }
+
+    //Pending transfers were never set in v0.1.0 & used a different layout
+    OWNERSHIP_TRANSFER.remove(deps.storage);
+
+    //Nothing is paused
+    PAUSE_STATE.save(deps.storage, &PauseState::default())?;
+
+    //v0.1.0 sales weren't tracked, so artist royalties accrue from the next sale
+    ROYALTY_STATE.save(deps.storage, &RoyaltyState::default())?;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(())
+}
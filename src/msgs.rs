@@ -1,7 +1,8 @@
-use cosmwasm_std::{Addr, Decimal, Uint128};
-use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use serde::Deserialize;
 
-use crate::state::SubmissionItem;
+use crate::state::{Auction, AuctionRecord, Bid, OwnershipTransfer, PauseState, SubmissionItem, Takedown};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -12,47 +13,245 @@ pub struct InstantiateMsg {
     /// Memecoin denom
     pub memecoin_denom: Option<String>,
     /// First submission for the first NFT auction of the collection
-    pub first_submission: SubmissionItem,
-    ///Mint cost
+    pub first_submission: FirstSubmission,
+    /// Mint fee paid to the base-minter per sold NFT, from the mint reserve.
+    /// Send ustars above the minter creation fee (250 STARS) to seed the reserve, FundMintReserve tops it up.
     pub mint_cost: u128,
+    /// Collection params, only the image is required
+    pub collection_params: CollectionParams,
 }
 
+/// The first auction's submission, it skips curation
 #[cw_serde]
+pub struct FirstSubmission {
+    pub submitter: String,
+    pub proceed_recipient: String,
+    /// Must be a valid URI (ipfs, https, etc.)
+    pub token_uri: String,
+    pub reserve_price: Option<u128>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct CollectionParams {
+    /// Must be a valid URI (ipfs, https, etc.)
+    pub image: String,
+    /// Defaults to The Memebrane
+    pub name: Option<String>,
+    /// Defaults to BRANE
+    pub symbol: Option<String>,
+    /// Defaults to a generic description using the collection name
+    pub description: Option<String>,
+    /// Defaults to no link
+    pub external_link: Option<String>,
+    /// Defaults to false
+    pub explicit_content: Option<bool>,
+    /// Can't be in the past
+    pub start_trading_time: Option<Timestamp>,
+    /// Defaults to 1%, at most 10%. 0 disables royalties.
+    pub royalty_share: Option<Decimal>,
+    /// Defaults to this contract
+    pub royalty_payment_address: Option<String>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     SubmitNFT { 
         submitter: String,
         proceed_recipient: String,
         token_uri: String,
+        /// Minimum winning bid in the bid_denom
+        reserve_price: Option<u128>,
     },
     /// Submissions have 7 days to get votes, after 7 days any votes will delete the submission
     VoteToCurate { submission_ids: Vec<u64>, vote: bool },
+    /// Bid the sent amount plus your claimable refund, or add them to your bid if you're the highest bidder
     Bid { },
     /// Transfer NFT to highest bidder & handle memecoin distributions
     ConcludeAuction { },
+    /// Claim the sender's distributed artist royalties
+    ClaimRoyalties {},
+    /// Claim the sender's refunded bids in every denom, never paused
+    ClaimRefunds {},
+    /// Add the sent ustars to the reserve that pays mint fees.
+    /// Auctions concluded while it can't cover the mint cost settle unsold.
+    FundMintReserve {},
     ////These are all controlled by the owner who will be a DAODAO NFT staking contract
-    MigrateMinter { new_address: String },
+    /// Cancel the live auction, crediting the highest bid's refund & starting the next pending auction.
+    /// The submission restarts curation under a new submission ID, within the submission limit, or is dropped.
+    CancelAuction { reason: String, return_to_curation: bool },
+    /// Split the contract's unreserved balance of the denom (defaults to the bid_denom) between artists, sinks & the treasury.
+    /// Royalties arrive as plain transfers, so the owner decides when that balance is royalties.
+    /// Artist shares are tracked in the bid_denom only, in other denoms (e.g. a previous bid_denom) they go to the treasury.
+    DistributeRoyalties { denom: Option<String> },
+    /// Point minting at a new base-minter whose collection creator is this contract.
+    /// Token IDs continue from the new minter's token index.
+    /// Optionally hand the old minter's admin rights to a successor, which requires this contract to be its wasm admin.
+    /// Refused while the live auction has bids or has ended without being concluded.
+    MigrateMinter { new_address: String, old_minter_successor: Option<String> },
     // MigrateContract { new_code_id: u64 },
-    UpdateConfig {
-        owner: Option<Addr>,
-        bid_denom: Option<String>,
-        memecoin_denom: Option<String>,
-        minter_addr: Option<String>, //do we need this and the migrate minter?
-    },
+    UpdateConfig(UpdateConfig),
+    /// Propose a new owner, who has to accept before the optional expiry (in seconds from now)
+    ProposeNewOwner { new_owner: String, expiry: Option<u64> },
+    /// Accept a pending ownership transfer as the proposed owner
+    AcceptOwnership {},
+    CancelOwnershipTransfer {},
+    /// Add or remove moderators
+    UpdateModerators { add: Vec<String>, remove: Vec<String> },
+    /// Remove a submission or pending auction for stolen or explicit content.
+    /// Callable by the owner or a moderator, the submitter's fee is optionally refunded.
+    TakeDownSubmission { submission_id: u64, reason: String, refund_fee: bool },
+    /// Send treasury funds to the recipient (defaults to the owner), all of the denom if no amount is set
+    WithdrawTreasury { denom: String, amount: Option<u128>, recipient: Option<String> },
+    /// Set or remove the guardian, who can pause but not unpause
+    UpdateGuardian { guardian: Option<String> },
+    /// Pause or unpause actions, unset fields are unchanged
+    UpdatePauseState(UpdatePauseState),
     //////
 }
 
 #[cw_serde]
+#[derive(Default)]
+pub struct UpdatePauseState {
+    pub submissions: Option<bool>,
+    pub curation: Option<bool>,
+    pub bidding: Option<bool>,
+    pub settlement: Option<bool>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct UpdateConfig {
+    /// Can't change while the live auction has bids escrowed
+    pub bid_denom: Option<String>,
+    pub memecoin_denom: Option<String>,
+    pub memecoin_distribution_amount: Option<u128>,
+    pub mint_cost: Option<u128>,
+    pub submission_cost: Option<u128>,
+    /// Can't be lower than the current submission total
+    pub submission_limit: Option<u64>,
+    /// In days, 1 to 30
+    pub submission_vote_period: Option<u64>,
+    /// Between 0 (exclusive) & 1
+    pub curation_threshold: Option<Decimal>,
+    /// In days, 1 to 7
+    pub auction_period: Option<u64>,
+    /// dao-voting-cw721-staked contract to source curation voting power from
+    pub voting_power_source: Option<String>,
+    /// Artist & sink shares can't total above 1
+    pub royalty_split: Option<RoyaltySplit>,
+    /// Absolute increments must be at least 1, percentages between 0 (exclusive) & 1
+    pub min_bid_increment: Option<BidIncrement>,
+    /// In seconds, 0 disables extensions
+    pub extension_window: Option<u64>,
+    /// In seconds, at most 1 day
+    pub extension_length: Option<u64>,
+    /// In seconds, at most 1 day
+    pub max_extension: Option<u64>,
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
 pub enum QueryMsg {
     /// Return contract config
+    #[returns(Config)]
     Config {},
     /// Return list of submissions
+    #[returns(SubmissionsResponse)]
     Submissions { limit: Option<u32>, start_after: Option<u64> },
+    /// Return the live auction & its bidding state
+    #[returns(LiveAuctionResponse)]
+    LiveAuction {},
+    /// Return the queue of curated submissions waiting to go live
+    #[returns(PendingAuctionsResponse)]
+    PendingAuctions { limit: Option<u32>, start_after: Option<u32> },
+    /// Return a submission's place in the pending auction queue
+    #[returns(PendingAuctionResponse)]
+    PendingAuctionPosition { submission_id: u64 },
+    /// Return queued auctions sent by the submitter, start_after is a queue position
+    #[returns(PendingAuctionsResponse)]
+    PendingAuctionsBySubmitter { submitter: String, limit: Option<u32>, start_after: Option<u32> },
+    /// Return queued auctions whose proceeds go to the recipient, start_after is a queue position
+    #[returns(PendingAuctionsResponse)]
+    PendingAuctionsByRecipient { recipient: String, limit: Option<u32>, start_after: Option<u32> },
+    /// Return submissions in curation sent by the submitter, curated ones are in PendingAuctionsBySubmitter & AuctionsBySubmitter
+    #[returns(SubmissionsResponse)]
+    SubmissionsBySubmitter { submitter: String, limit: Option<u32>, start_after: Option<u64> },
+    /// Return submissions in curation whose proceeds go to the recipient, curated ones are in PendingAuctionsByRecipient & AuctionsByArtist
+    #[returns(SubmissionsResponse)]
+    SubmissionsByRecipient { recipient: String, limit: Option<u32>, start_after: Option<u64> },
+    /// Return a bidder's bids, grouped by the submission ID of the auction
+    #[returns(BidderBidsResponse)]
+    BidsByBidder { bidder: String, limit: Option<u32>, start_after: Option<u64> },
+    /// Return the submission IDs a curator voted for
+    #[returns(CuratorVotesResponse)]
+    VotesByCurator { curator: String, limit: Option<u32>, start_after: Option<u64> },
+    /// Return concluded auctions by auction ID
+    #[returns(AuctionHistoryResponse)]
+    AuctionHistory { limit: Option<u32>, start_after: Option<u64> },
+    /// Return the concluded auction that minted the token
+    #[returns(AuctionRecord)]
+    AuctionByTokenId { token_id: String },
+    /// Return concluded auctions won by the winner
+    #[returns(AuctionHistoryResponse)]
+    AuctionsByWinner { winner: String, limit: Option<u32>, start_after: Option<u64> },
+    /// Return concluded auctions whose proceeds & royalties went to the artist (proceed recipient)
+    #[returns(AuctionHistoryResponse)]
+    AuctionsByArtist { artist: String, limit: Option<u32>, start_after: Option<u64> },
+    /// Return concluded auctions of submissions sent by the submitter
+    #[returns(AuctionHistoryResponse)]
+    AuctionsBySubmitter { submitter: String, limit: Option<u32>, start_after: Option<u64> },
+    /// Return the pending ownership transfer, if any
+    #[returns(Option<OwnershipTransfer>)]
+    OwnershipTransfer {},
+    /// Return an address's claimable bid refunds
+    #[returns(RefundsResponse)]
+    Refunds { address: String },
+    /// Return the takedown of a submission
+    #[returns(Takedown)]
+    Takedown { submission_id: u64 },
+    /// Return takedowns by submission ID
+    #[returns(TakedownsResponse)]
+    Takedowns { limit: Option<u32>, start_after: Option<u64> },
+    /// Return an artist's royalties
+    #[returns(ArtistRoyaltiesResponse)]
+    ArtistRoyalties { artist: String },
+    /// Return treasury balances
+    #[returns(TreasuryResponse)]
+    Treasury {},
+    /// Return the funds set aside to pay mint fees
+    #[returns(Coin)]
+    MintReserve {},
+    /// Return which actions are paused
+    #[returns(PauseState)]
+    PauseState {},
+}
+
+#[cw_serde]
+pub struct SubmissionResponse {
+    /// Submission ID
+    pub submission_id: u64,
+    /// Submission info & curation status
+    pub submission: SubmissionItem,
+}
+
+#[cw_serde]
+pub struct SubmissionsResponse {
+    pub submissions: Vec<SubmissionResponse>,
 }
 
 #[cw_serde]
 pub struct Config {
     /// Contract owner
     pub owner: Addr,
+    /// Can pause actions, only the owner can unpause
+    pub guardian: Option<Addr>,
+    /// Can take down submissions & pending auctions
+    pub moderators: Vec<Addr>,
     /// Bid denom
     pub bid_denom: String,
     /// Memecoin denom
@@ -61,10 +260,14 @@ pub struct Config {
     pub memecoin_distribution_amount: u128,
     /// Current token ID
     pub current_token_id: u64,
+    /// Current auction ID, incremented as auctions are concluded
+    pub current_auction_id: u64,
     /// Current submission ID
     pub current_submission_id: u64,
-    /// Minter address
+    /// Base minter address
     pub minter_addr: String,
+    /// sg721 collection address
+    pub collection_addr: String,
     /// Stargaze Mint cost 
     /// Testnet: 50_000_000u128
     /// Mainnet: 5_000_000_000u128
@@ -81,4 +284,153 @@ pub struct Config {
     pub curation_threshold: Decimal,
     /// Auction period (in days)
     pub auction_period: u64, 
+    /// dao-voting-cw721-staked contract whose staked voting power weighs curation votes.
+    /// If None, each collection holder has 1 vote out of the collection's supply.
+    pub voting_power_source: Option<Addr>,
+    /// Royalty distribution shares
+    pub royalty_split: RoyaltySplit,
+    /// Minimum amount a bid must outbid the highest bid by
+    pub min_bid_increment: BidIncrement,
+    /// Bids within this many seconds of the end time extend the auction
+    pub extension_window: u64,
+    /// Late bids push the end time to this many seconds after the bid
+    pub extension_length: u64,
+    /// Total seconds an auction can be extended by
+    pub max_extension: u64,
+}
+
+#[cw_serde]
+pub enum BidIncrement {
+    /// Amount in the bid_denom
+    Absolute(u128),
+    /// Percent of the highest bid, rounded down to at least 1
+    Percent(Decimal),
+}
+
+#[cw_serde]
+pub struct RoyaltySplit {
+    /// Split between the artists of sold tokens, pro rata to tokens sold
+    pub artist_share: Decimal,
+    /// Other royalty recipients
+    pub sinks: Vec<RoyaltySink>,
+    //The treasury receives the rest
+}
+
+#[cw_serde]
+pub struct RoyaltySink {
+    pub address: Addr,
+    pub share: Decimal,
+}
+
+#[cw_serde]
+pub struct TreasuryResponse {
+    pub balances: Vec<Coin>,
+    /// Fees of submissions that aren't finalized yet, these can't be withdrawn
+    pub held_submission_fees: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct RefundsResponse {
+    pub address: Addr,
+    pub refunds: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct ArtistRoyaltiesResponse {
+    pub artist: Addr,
+    pub token_count: u64,
+    /// Claimable royalties in the bid_denom
+    pub claimable: u128,
+}
+
+#[cw_serde]
+pub struct LiveAuctionResponse {
+    /// None if there is no live auction
+    pub auction: Option<LiveAuctionInfo>,
+}
+
+#[cw_serde]
+pub struct LiveAuctionInfo {
+    pub auction: Auction,
+    /// Seconds until the auction can be concluded
+    pub seconds_remaining: u64,
+    /// Current highest bid
+    pub highest_bid: Bid,
+    /// Minimum amount the next bid must be in the bid_denom
+    pub minimum_next_bid: u128,
+    pub token_uri: String,
+    pub proceed_recipient: Addr,
+    /// The auction settles unsold if the highest bid is below this
+    pub reserve_price: Option<u128>,
+}
+
+#[cw_serde]
+pub struct PendingAuctionResponse {
+    /// Index in the queue, 0 is next to go live
+    pub position: u32,
+    pub auction: Auction,
+    /// Estimated start time (in seconds) assuming each auction is concluded on time
+    pub estimated_start_time: u64,
+}
+
+#[cw_serde]
+pub struct PendingAuctionsResponse {
+    pub pending_auctions: Vec<PendingAuctionResponse>,
+}
+
+#[cw_serde]
+pub struct AuctionBids {
+    /// Submission ID of the auctioned submission
+    pub submission_id: u64,
+    /// True if this is the live auction
+    pub live: bool,
+    /// Bid amounts in the order they were placed
+    pub bids: Vec<u128>,
+}
+
+#[cw_serde]
+pub struct BidderBidsResponse {
+    pub bidder: Addr,
+    pub auctions: Vec<AuctionBids>,
+}
+
+#[cw_serde]
+pub struct CuratorVotesResponse {
+    pub curator: Addr,
+    pub submission_ids: Vec<u64>,
+}
+
+#[cw_serde]
+pub struct AuctionHistoryResponse {
+    pub auctions: Vec<AuctionRecord>,
+}
+
+#[cw_serde]
+pub struct TakedownsResponse {
+    pub takedowns: Vec<Takedown>,
+}
+
+/// Subset of the base-minter's ConfigResponse, extra fields are ignored
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct MinterConfigResponse {
+    pub collection_address: String,
+}
+
+/// Voting power queries of a dao-voting-cw721-staked contract
+#[cw_serde]
+pub enum VotingPowerQueryMsg {
+    VotingPowerAtHeight { address: String, height: Option<u64> },
+    TotalPowerAtHeight { height: Option<u64> },
+}
+
+#[cw_serde]
+pub struct VotingPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct TotalPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
 }
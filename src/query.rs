@@ -0,0 +1,466 @@
+use cosmwasm_std::{Coin, Deps, Env, Order, StdError, StdResult, Uint128};
+use cw_storage_plus::Bound;
+
+use crate::{contracts::{MINT_DENOM, SECONDS_PER_DAY}, msgs::{ArtistRoyaltiesResponse, AuctionBids, BidIncrement, AuctionHistoryResponse, BidderBidsResponse, Config, CuratorVotesResponse, LiveAuctionInfo, LiveAuctionResponse, PendingAuctionResponse, PendingAuctionsResponse, RefundsResponse, SubmissionResponse, SubmissionsResponse, TakedownsResponse, TreasuryResponse}, state::{auction_history, ARTIST_ROYALTIES, ROYALTY_STATE, submissions, Auction, AuctionRecord, OwnershipTransfer, PauseState, Takedown, AUCTION, BIDDER_BIDS, CONFIG, CURATOR_VOTES, HELD_SUBMISSION_FEES, OWNERSHIP_TRANSFER, PAUSE_STATE, PENDING_AUCTION, MINT_RESERVE, REFUNDS, TAKEDOWNS, TREASURY}};
+
+//Pagination
+const DEFAULT_LIMIT: u32 = 10u32;
+const MAX_LIMIT: u32 = 32u32;
+
+pub fn query_config(deps: Deps) -> StdResult<Config> {
+    CONFIG.load(deps.storage)
+}
+
+pub fn query_ownership_transfer(deps: Deps) -> StdResult<Option<OwnershipTransfer>> {
+    OWNERSHIP_TRANSFER.may_load(deps.storage)
+}
+
+pub fn query_pause_state(deps: Deps) -> StdResult<PauseState> {
+    PAUSE_STATE.load(deps.storage)
+}
+
+pub fn query_submissions(
+    deps: Deps,
+    _env: Env,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<SubmissionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let submissions = submissions()
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (submission_id, submission) = item?;
+            Ok(SubmissionResponse { submission_id, submission })
+        })
+        .collect::<StdResult<Vec<SubmissionResponse>>>()?;
+
+    Ok(SubmissionsResponse { submissions })
+}
+
+/// Minimum amount a bid must be to outbid the current highest bid
+pub fn get_minimum_next_bid(config: &Config, auction: &Auction) -> u128 {
+    //Any bid is accepted first
+    if auction.highest_bid.amount == 0 {
+        return 1u128;
+    }
+    let increment = match config.min_bid_increment {
+        BidIncrement::Absolute(amount) => amount,
+        BidIncrement::Percent(percent) => (Uint128::new(auction.highest_bid.amount) * percent).u128(),
+    };
+
+    auction.highest_bid.amount + increment.max(1)
+}
+
+pub fn query_live_auction(
+    deps: Deps,
+    env: Env,
+) -> StdResult<LiveAuctionResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    //Return an empty response if there is no live auction
+    let live_auction = match AUCTION.may_load(deps.storage)? {
+        Some(auction) => auction,
+        None => return Ok(LiveAuctionResponse { auction: None }),
+    };
+
+    Ok(LiveAuctionResponse {
+        auction: Some(LiveAuctionInfo {
+            seconds_remaining: live_auction.auction_end_time.saturating_sub(env.block.time.seconds()),
+            highest_bid: live_auction.highest_bid.clone(),
+            minimum_next_bid: get_minimum_next_bid(&config, &live_auction),
+            token_uri: live_auction.submission_info.submission.token_uri.clone(),
+            proceed_recipient: live_auction.submission_info.submission.proceed_recipient.clone(),
+            reserve_price: live_auction.submission_info.submission.reserve_price,
+            auction: live_auction,
+        }),
+    })
+}
+
+/// Estimated start time of the pending auction at the given queue position.
+/// Position 0 starts when the live auction ends, or now if there is none.
+fn get_estimated_start_time(
+    deps: Deps,
+    env: &Env,
+    auction_period: u64,
+    position: u32,
+) -> StdResult<u64> {
+    let queue_start = match AUCTION.may_load(deps.storage)? {
+        Some(live_auction) => live_auction.auction_end_time.max(env.block.time.seconds()),
+        None => env.block.time.seconds(),
+    };
+
+    Ok(queue_start + (position as u64 * auction_period * SECONDS_PER_DAY))
+}
+
+pub fn query_pending_auctions(
+    deps: Deps,
+    env: Env,
+    limit: Option<u32>,
+    start_after: Option<u32>,
+) -> StdResult<PendingAuctionsResponse> {
+    get_pending_auctions(deps, env, limit, start_after, |_| true)
+}
+
+pub fn query_pending_auctions_by_submitter(
+    deps: Deps,
+    env: Env,
+    submitter: String,
+    limit: Option<u32>,
+    start_after: Option<u32>,
+) -> StdResult<PendingAuctionsResponse> {
+    let submitter = deps.api.addr_validate(&submitter)?;
+
+    get_pending_auctions(deps, env, limit, start_after, |auction| auction.submission_info.submission.submitter == submitter)
+}
+
+pub fn query_pending_auctions_by_recipient(
+    deps: Deps,
+    env: Env,
+    recipient: String,
+    limit: Option<u32>,
+    start_after: Option<u32>,
+) -> StdResult<PendingAuctionsResponse> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    get_pending_auctions(deps, env, limit, start_after, |auction| auction.submission_info.submission.proceed_recipient == recipient)
+}
+
+//The queue is a single item, so filtered views scan it instead of keeping indexes
+fn get_pending_auctions(
+    deps: Deps,
+    env: Env,
+    limit: Option<u32>,
+    start_after: Option<u32>,
+    filter: impl Fn(&Auction) -> bool,
+) -> StdResult<PendingAuctionsResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|position| position + 1).unwrap_or(0) as usize;
+
+    let pending_auctions = PENDING_AUCTION.load(deps.storage)?
+        .into_iter()
+        .enumerate()
+        .skip(start)
+        .filter(|(_, auction)| filter(auction))
+        .take(limit)
+        .map(|(position, auction)| {
+            Ok(PendingAuctionResponse {
+                position: position as u32,
+                estimated_start_time: get_estimated_start_time(deps, &env, config.auction_period, position as u32)?,
+                auction,
+            })
+        })
+        .collect::<StdResult<Vec<PendingAuctionResponse>>>()?;
+
+    Ok(PendingAuctionsResponse { pending_auctions })
+}
+
+pub fn query_pending_auction_position(
+    deps: Deps,
+    env: Env,
+    submission_id: u64,
+) -> StdResult<PendingAuctionResponse> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let (position, auction) = PENDING_AUCTION.load(deps.storage)?
+        .into_iter()
+        .enumerate()
+        .find(|(_, auction)| auction.submission_id == submission_id)
+        .ok_or_else(|| StdError::generic_err(format!("Submission {} is not in the pending auction queue", submission_id)))?;
+
+    Ok(PendingAuctionResponse {
+        position: position as u32,
+        estimated_start_time: get_estimated_start_time(deps, &env, config.auction_period, position as u32)?,
+        auction,
+    })
+}
+
+pub fn query_submissions_by_submitter(
+    deps: Deps,
+    submitter: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<SubmissionsResponse> {
+    let submitter = deps.api.addr_validate(&submitter)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let submissions = submissions()
+        .idx
+        .submitter
+        .prefix(submitter)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (submission_id, submission) = item?;
+            Ok(SubmissionResponse { submission_id, submission })
+        })
+        .collect::<StdResult<Vec<SubmissionResponse>>>()?;
+
+    Ok(SubmissionsResponse { submissions })
+}
+
+pub fn query_submissions_by_recipient(
+    deps: Deps,
+    recipient: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<SubmissionsResponse> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let submissions = submissions()
+        .idx
+        .proceed_recipient
+        .prefix(recipient)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (submission_id, submission) = item?;
+            Ok(SubmissionResponse { submission_id, submission })
+        })
+        .collect::<StdResult<Vec<SubmissionResponse>>>()?;
+
+    Ok(SubmissionsResponse { submissions })
+}
+
+pub fn query_bids_by_bidder(
+    deps: Deps,
+    bidder: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<BidderBidsResponse> {
+    let bidder = deps.api.addr_validate(&bidder)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    //Used to flag bids on the live auction
+    let live_submission_id = AUCTION.may_load(deps.storage)?.map(|auction| auction.submission_id);
+
+    let auctions = BIDDER_BIDS
+        .prefix(&bidder)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (submission_id, bids) = item?;
+            Ok(AuctionBids {
+                submission_id,
+                live: live_submission_id == Some(submission_id),
+                bids,
+            })
+        })
+        .collect::<StdResult<Vec<AuctionBids>>>()?;
+
+    Ok(BidderBidsResponse { bidder, auctions })
+}
+
+pub fn query_votes_by_curator(
+    deps: Deps,
+    curator: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<CuratorVotesResponse> {
+    let curator = deps.api.addr_validate(&curator)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let submission_ids = CURATOR_VOTES
+        .prefix(&curator)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    Ok(CuratorVotesResponse { curator, submission_ids })
+}
+
+pub fn query_auction_history(
+    deps: Deps,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<AuctionHistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let auctions = auction_history()
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect::<StdResult<Vec<AuctionRecord>>>()?;
+
+    Ok(AuctionHistoryResponse { auctions })
+}
+
+pub fn query_auction_by_token_id(
+    deps: Deps,
+    token_id: String,
+) -> StdResult<AuctionRecord> {
+    if token_id.is_empty() {
+        return Err(StdError::generic_err("Token ID can't be empty"));
+    }
+
+    auction_history()
+        .idx
+        .token_id
+        .prefix(token_id.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        //Unsold auctions have no token ID
+        .find(|item| !matches!(item, Ok((_, record)) if record.token_id.is_none()))
+        .ok_or_else(|| StdError::generic_err(format!("No auction found for token {}", token_id)))?
+        .map(|(_, record)| record)
+}
+
+pub fn query_auctions_by_winner(
+    deps: Deps,
+    winner: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<AuctionHistoryResponse> {
+    let winner = deps.api.addr_validate(&winner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let auctions = auction_history()
+        .idx
+        .winner
+        .prefix(winner)
+        .range(deps.storage, start, None, Order::Ascending)
+        //Unsold auctions have no winner
+        .filter(|item| !matches!(item, Ok((_, record)) if record.winner.is_none()))
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect::<StdResult<Vec<AuctionRecord>>>()?;
+
+    Ok(AuctionHistoryResponse { auctions })
+}
+
+pub fn query_auctions_by_artist(
+    deps: Deps,
+    artist: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<AuctionHistoryResponse> {
+    let artist = deps.api.addr_validate(&artist)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let auctions = auction_history()
+        .idx
+        .artist
+        .prefix(artist)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect::<StdResult<Vec<AuctionRecord>>>()?;
+
+    Ok(AuctionHistoryResponse { auctions })
+}
+
+pub fn query_auctions_by_submitter(
+    deps: Deps,
+    submitter: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<AuctionHistoryResponse> {
+    let submitter = deps.api.addr_validate(&submitter)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let auctions = auction_history()
+        .idx
+        .submitter
+        .prefix(submitter)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect::<StdResult<Vec<AuctionRecord>>>()?;
+
+    Ok(AuctionHistoryResponse { auctions })
+}
+
+pub fn query_takedown(
+    deps: Deps,
+    submission_id: u64,
+) -> StdResult<Takedown> {
+    TAKEDOWNS.load(deps.storage, submission_id)
+}
+
+pub fn query_takedowns(
+    deps: Deps,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<TakedownsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let takedowns = TAKEDOWNS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect::<StdResult<Vec<Takedown>>>()?;
+
+    Ok(TakedownsResponse { takedowns })
+}
+
+pub fn query_artist_royalties(
+    deps: Deps,
+    artist: String,
+) -> StdResult<ArtistRoyaltiesResponse> {
+    let artist = deps.api.addr_validate(&artist)?;
+    let royalty_state = ROYALTY_STATE.load(deps.storage)?;
+
+    //Include royalties distributed since the artist was last settled
+    let (token_count, claimable) = match ARTIST_ROYALTIES.may_load(deps.storage, &artist)? {
+        Some(artist_royalties) => {
+            let accrued = Uint128::from(artist_royalties.token_count) * (royalty_state.index - artist_royalties.index);
+            (artist_royalties.token_count, artist_royalties.unclaimed + accrued.u128())
+        },
+        None => (0, 0),
+    };
+
+    Ok(ArtistRoyaltiesResponse { artist, token_count, claimable })
+}
+
+pub fn query_refunds(
+    deps: Deps,
+    address: String,
+) -> StdResult<RefundsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let refunds = REFUNDS
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(Coin { denom, amount: Uint128::new(amount) })
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    Ok(RefundsResponse { address, refunds })
+}
+
+pub fn query_treasury(deps: Deps) -> StdResult<TreasuryResponse> {
+    let balances = TREASURY
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(Coin { denom, amount: Uint128::new(amount) })
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+    let held_submission_fees = HELD_SUBMISSION_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(Coin { denom, amount: Uint128::new(amount) })
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    Ok(TreasuryResponse { balances, held_submission_fees })
+}
+
+pub fn query_mint_reserve(deps: Deps) -> StdResult<Coin> {
+    let amount = MINT_RESERVE.may_load(deps.storage)?.unwrap_or_default();
+
+    Ok(Coin { denom: String::from(MINT_DENOM), amount: Uint128::new(amount) })
+}
@@ -1,50 +1,64 @@
 use cosmwasm_std::{DepsMut, Env, Reply, Response, StdError, StdResult};
+use cw721_base::msg::MinterResponse;
+use sg721_base::msg::QueryMsg as Sg721QueryMsg;
 
 use crate::state::CONFIG;
 
 
 
-pub fn handle_collection_reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
+pub fn handle_collection_reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
     match msg.result.into_result() {
         Ok(result) => {
-            
-            // let mint_event = result
-            //     .events
-            //     .iter()
-            //     .find(|e| {
-            //         e.attributes
-            //             .iter()
-            //             .any(|attr| attr.key == "_contract_address")
-            //     })
-            //     .ok_or_else(|| {
-            //         StdError::GenericErr { msg: String::from("unable to find mint event") }
-            //     })?;
-
-            panic!("Events: {:?}", result.events);
+            // The factory instantiates the base-minter, which instantiates the sg721 collection
+            // & returns its address in the "sg721_address" attribute of its reply
+            let minter_event = result
+                .events
+                .iter()
+                .find(|e| e.attributes.iter().any(|attr| attr.key == "sg721_address"))
+                .ok_or_else(|| {
+                    StdError::GenericErr { msg: String::from("unable to find collection address") }
+                })?;
+            let collection_addr = minter_event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "sg721_address")
+                .map(|attr| attr.value.clone())
+                .unwrap_or_default();
+
+            // The minter emitted that event, so it's tagged w/ the minter's address.
+            // wasmd tags it "_contract_address", cw-multi-test "_contract_addr"
+            let minter_addr = match minter_event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "_contract_address" || attr.key == "_contract_addr")
+            {
+                Some(attr) => attr.value.clone(),
+                // Fall back to asking the collection for its minter
+                None => deps.querier
+                    .query_wasm_smart::<MinterResponse>(collection_addr.clone(), &Sg721QueryMsg::Minter {})?
+                    .minter
+                    .ok_or_else(|| {
+                        StdError::GenericErr { msg: String::from("unable to find minter address") }
+                    })?,
+            };
 
             //Load config
             let mut config = CONFIG.load(deps.storage)?;
 
-            //Save mint address
-            // config.minter_addr = mint_event
-            //     .attributes
-            //     .iter()
-            //     .find(|attr| attr.key == "_contract_address")
-            //     .ok_or_else(|| {
-            //         StdError::GenericErr { msg: String::from("unable to find mint address") }
-            //     })?
-            //     .value
-            //     .clone();
-            todo!("Save the minter address to storage on success");
+            //Save minter & collection addresses
+            config.minter_addr = deps.api.addr_validate(&minter_addr)?.to_string();
+            config.collection_addr = deps.api.addr_validate(&collection_addr)?.to_string();
+            CONFIG.save(deps.storage, &config)?;
 
             Ok(Response::new()
-            .add_attribute("mint_addr", config.clone().minter_addr))
+            .add_attribute("minter_addr", config.minter_addr)
+            .add_attribute("collection_addr", config.collection_addr))
         },
-        
+
         Err(err) => {
             //Its reply on success only
-            Ok(Response::new().add_attribute("error", err))
+            Err(StdError::GenericErr { msg: err })
         }
-    }    
+    }
 
-}
\ No newline at end of file
+}
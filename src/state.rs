@@ -1,6 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cw_storage_plus::{Item, Map};
-use cosmwasm_std::Addr;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cosmwasm_std::{Addr, Coin, Decimal};
 
 use crate::msgs::Config;
 
@@ -22,27 +22,180 @@ pub struct SubmissionInfo {
     pub submitter: Addr,
     pub proceed_recipient: Addr,
     pub token_uri: String,
+    /// Auctions with a highest bid below this settle unsold
+    pub reserve_price: Option<u128>,
 }
 #[cw_serde]
 pub struct SubmissionItem {
     pub submission: SubmissionInfo,
     pub curation_votes: Vec<Addr>,
+    /// Yes voting power, 1 per holder without a voting power source
+    pub curation_power: u128,
+    /// Voting power is queried at this height
+    pub submission_height: u64,
     pub submission_end_time: u64, //in seconds
+    /// Fee paid by a non-holder, refundable on takedown
+    pub submission_fee: Option<Coin>,
 }
 
 #[cw_serde]
 pub struct Auction {
+    pub submission_id: u64,
     pub submission_info: SubmissionItem,
     pub bids: Vec<Bid>,
     pub highest_bid: Bid,
+    pub auction_start_time: u64, //in seconds
     pub auction_end_time: u64, //in seconds
+    /// Seconds added to the end time by late bids
+    pub total_extension: u64,
+}
+
+#[cw_serde]
+pub struct OwnershipTransfer {
+    pub new_owner: Addr,
+    /// Proposal can't be accepted after this time (in seconds)
+    pub expiry: Option<u64>,
+}
+
+/// Actions the owner or guardian can halt independently.
+/// Refunds to outbid bidders are never paused.
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseState {
+    /// Halts SubmitNFT
+    pub submissions: bool,
+    /// Halts VoteToCurate
+    pub curation: bool,
+    /// Halts Bid
+    pub bidding: bool,
+    /// Halts ConcludeAuction
+    pub settlement: bool,
+}
+
+#[cw_serde]
+pub struct Takedown {
+    pub submission_id: u64,
+    pub submission: SubmissionInfo,
+    pub reason: String,
+    pub moderator: Addr,
+    /// Fee refunded to the submitter, if any
+    pub refunded_fee: Option<Coin>,
+    pub time: u64, //in seconds
+}
+
+/// Artist royalties accrue per sold token, so distributions don't iterate over artists
+#[cw_serde]
+#[derive(Default)]
+pub struct RoyaltyState {
+    /// Cumulative royalties per sold token
+    pub index: Decimal,
+    /// Tokens sold through this contract
+    pub token_count: u64,
+    /// Royalties distributed to artists but not yet claimed
+    pub artist_owed: u128,
+}
+
+#[cw_serde]
+pub struct ArtistRoyalties {
+    pub token_count: u64,
+    /// RoyaltyState index when last settled
+    pub index: Decimal,
+    pub unclaimed: u128,
+}
+
+#[cw_serde]
+pub struct AuctionRecord {
+    pub auction_id: u64,
+    pub submission_id: u64,
+    /// None if the auction concluded without bids
+    pub token_id: Option<String>,
+    pub winner: Option<Addr>,
+    pub winning_amount: u128,
+    pub submitter: Addr,
+    pub proceed_recipient: Addr,
+    pub token_uri: String,
+    pub curators: Vec<Addr>,
+    pub bid_count: u64,
+    pub start_time: u64, //in seconds
+    pub end_time: u64, //in seconds
+    /// Total memecoins sent to bidders & curators
+    pub memecoins_distributed: u128,
+    /// Set if the owner cancelled the auction
+    pub cancel_reason: Option<String>,
+}
+
+
+pub struct SubmissionIndexes<'a> {
+    pub submitter: MultiIndex<'a, Addr, SubmissionItem, u64>,
+    pub proceed_recipient: MultiIndex<'a, Addr, SubmissionItem, u64>,
+}
+
+impl<'a> IndexList<SubmissionItem> for SubmissionIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SubmissionItem>> + '_> {
+        let v: Vec<&dyn Index<SubmissionItem>> = vec![&self.submitter, &self.proceed_recipient];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Submissions in curation, indexed by submitter & proceed recipient
+pub fn submissions<'a>() -> IndexedMap<'a, u64, SubmissionItem, SubmissionIndexes<'a>> {
+    let indexes = SubmissionIndexes {
+        submitter: MultiIndex::new(|d: &SubmissionItem| d.submission.submitter.clone(), "submissions", "submissions__submitter"),
+        proceed_recipient: MultiIndex::new(|d: &SubmissionItem| d.submission.proceed_recipient.clone(), "submissions", "submissions__proceed_recipient"),
+    };
+    IndexedMap::new("submissions", indexes)
+}
+
+pub struct AuctionHistoryIndexes<'a> {
+    pub token_id: MultiIndex<'a, String, AuctionRecord, u64>,
+    pub winner: MultiIndex<'a, Addr, AuctionRecord, u64>,
+    pub artist: MultiIndex<'a, Addr, AuctionRecord, u64>,
+    pub submitter: MultiIndex<'a, Addr, AuctionRecord, u64>,
+}
+
+impl<'a> IndexList<AuctionRecord> for AuctionHistoryIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<AuctionRecord>> + '_> {
+        let v: Vec<&dyn Index<AuctionRecord>> = vec![&self.token_id, &self.winner, &self.artist, &self.submitter];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Concluded auctions keyed by auction ID, indexed by token ID, winner, artist (proceed recipient) & submitter.
+/// Unsold auctions are indexed under an empty token ID & winner, so queries skip them.
+pub fn auction_history<'a>() -> IndexedMap<'a, u64, AuctionRecord, AuctionHistoryIndexes<'a>> {
+    let indexes = AuctionHistoryIndexes {
+        token_id: MultiIndex::new(|d: &AuctionRecord| d.token_id.clone().unwrap_or_default(), "auction_history", "auction_history__token_id"),
+        winner: MultiIndex::new(|d: &AuctionRecord| d.winner.clone().unwrap_or_else(|| Addr::unchecked("")), "auction_history", "auction_history__winner"),
+        artist: MultiIndex::new(|d: &AuctionRecord| d.proceed_recipient.clone(), "auction_history", "auction_history__artist"),
+        submitter: MultiIndex::new(|d: &AuctionRecord| d.submitter.clone(), "auction_history", "auction_history__submitter"),
+    };
+    IndexedMap::new("auction_history", indexes)
 }
 
 
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const SUBMISSIONS: Map<u64, SubmissionItem> = Map::new("submissions");
 pub const PENDING_AUCTION: Item<Vec<Auction>> = Item::new("pending_auctions");
 pub const AUCTION: Item<Auction> = Item::new("current_auction");
+/// Bid amounts per (bidder, submission_id) of the auction they bid on
+pub const BIDDER_BIDS: Map<(&Addr, u64), Vec<u128>> = Map::new("bidder_bids");
+/// Yes votes per (curator, submission_id)
+pub const CURATOR_VOTES: Map<(&Addr, u64), bool> = Map::new("curator_votes");
 
 
-pub const OWNERSHIP_TRANSFER: Item<Addr> = Item::new("ownership_transfer");
\ No newline at end of file
+pub const OWNERSHIP_TRANSFER: Item<OwnershipTransfer> = Item::new("ownership_transfer");
+pub const PAUSE_STATE: Item<PauseState> = Item::new("pause_state");
+pub const TAKEDOWNS: Map<u64, Takedown> = Map::new("takedowns");
+pub const ROYALTY_STATE: Item<RoyaltyState> = Item::new("royalty_state");
+/// Royalties per proceed_recipient of sold tokens
+pub const ARTIST_ROYALTIES: Map<&Addr, ArtistRoyalties> = Map::new("artist_royalties");
+/// Treasury funds per denom, only these can be withdrawn by the owner
+pub const TREASURY: Map<&str, u128> = Map::new("treasury");
+/// Claimable bid refunds per (bidder, denom)
+pub const REFUNDS: Map<(&Addr, &str), u128> = Map::new("refunds");
+/// Total claimable bid refunds per denom
+pub const REFUNDS_OWED: Map<&str, u128> = Map::new("refunds_owed");
+/// Funds set aside to pay mint fees, in the mint denom
+pub const MINT_RESERVE: Item<u128> = Item::new("mint_reserve");
+/// Submission fees per denom of submissions in curation or pending auction.
+/// Held outside the treasury so takedowns can refund them, they move to the treasury once the submission is finalized.
+pub const HELD_SUBMISSION_FEES: Map<&str, u128> = Map::new("held_submission_fees");